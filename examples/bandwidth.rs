@@ -2,7 +2,9 @@ extern crate reed_solomon;
 extern crate rustc_serialize;
 
 use reed_solomon::Encoder;
+use reed_solomon::TableEncoder;
 use reed_solomon::Decoder;
+use rustc_serialize::{Encodable, Encoder as Serializer};
 
 struct Generator {
     pub num: u8
@@ -28,21 +30,18 @@ use std::thread;
 use std::time::Duration;
 use std::sync::mpsc;
 
-// Returns MB/s
-fn encoder_bandwidth(data_len: usize, ecc_len: usize) -> f32 { 
-     // Measure encoding bandwidth
+// Number of codewords encoded or checked in lockstep by batch functions
+const LANES: usize = 32;
+
+// Runs `step`, which returns the number of processed bytes, for a second. Returns MB/s
+fn measure<F: FnMut() -> usize + Send + 'static>(mut step: F) -> f32 {
     let (tx, thr_rx) = mpsc::channel();
     let (thr_tx, rx) = mpsc::channel();
-    
-    thread::spawn(move || {
-        let generator = Generator::new();
-        let encoder = Encoder::new(ecc_len);
 
-        let buffer: Vec<u8> = generator.take(data_len).collect();
+    thread::spawn(move || {
         let mut bytes = 0;
         while thr_rx.try_recv().is_err() {
-            encoder.encode(&buffer);
-            bytes += data_len;
+            bytes += step();
         }
 
         thr_tx.send(bytes).unwrap();
@@ -56,76 +55,163 @@ fn encoder_bandwidth(data_len: usize, ecc_len: usize) -> f32 {
     kbytes / 1024.0
 }
 
+// Returns MB/s
+fn encoder_bandwidth(data_len: usize, ecc_len: usize) -> f32 {
+    let encoder = Encoder::new(ecc_len);
+    let buffer: Vec<u8> = Generator::new().take(data_len).collect();
+
+    measure(move || {
+        encoder.encode(&buffer);
+        data_len
+    })
+}
+
+// Returns MB/s
+fn table_encoder_bandwidth(data_len: usize, ecc_len: usize) -> f32 {
+    let encoder = TableEncoder::new(ecc_len);
+    let buffer: Vec<u8> = Generator::new().take(data_len).collect();
+
+    measure(move || {
+        encoder.encode(&buffer);
+        data_len
+    })
+}
+
+// Returns MB/s of `LANES` codewords encoded at once
+fn batch_encoder_bandwidth(data_len: usize, ecc_len: usize) -> f32 {
+    let encoder = Encoder::new(ecc_len);
+    let mut codewords: [Vec<u8>; LANES] = core::array::from_fn(|_| {
+        let mut codeword: Vec<u8> = Generator::new().take(data_len).collect();
+        codeword.resize(data_len + ecc_len, 0);
+        codeword
+    });
+
+    measure(move || {
+        encoder.encode_batch(&mut codewords).unwrap();
+        data_len * LANES
+    })
+}
+
 // Returns MB/s
 fn decoder_bandwidth(data_len: usize, ecc_len: usize, errors: usize) -> f32 {
-     // Measure decoder bandwidth
-    let (tx, thr_rx) = mpsc::channel();
-    let (thr_tx, rx) = mpsc::channel();
-    
-    thread::spawn(move || {
-        let generator = Generator::new();
-        let encoder = Encoder::new(ecc_len);
-        let decoder = Decoder::new(ecc_len);
+    let encoder = Encoder::new(ecc_len);
+    let decoder = Decoder::new(ecc_len);
 
-        let buffer: Vec<u8> = generator.take(data_len).collect();
-        let mut encoded = encoder.encode(&buffer);
-        for x in encoded.iter_mut().take(errors) {
-            *x = 0;
-        } 
+    let buffer: Vec<u8> = Generator::new().take(data_len).collect();
+    let mut encoded = encoder.encode(&buffer);
+    for x in encoded.iter_mut().take(errors) {
+        *x = 0;
+    }
 
-        let mut bytes = 0;
-        while thr_rx.try_recv().is_err() {
-            if decoder.is_corrupted(&encoded) {
-                decoder.correct(&mut encoded, None).unwrap();
-            }            
-            bytes += data_len;
+    measure(move || {
+        if decoder.is_corrupted(&encoded) {
+            decoder.correct(&encoded, None).unwrap();
         }
+        data_len
+    })
+}
 
-        thr_tx.send(bytes).unwrap();
-    });
+// Returns MB/s of `LANES` uncorrupted codewords checked at once
+fn batch_check_bandwidth(data_len: usize, ecc_len: usize) -> f32 {
+    let encoder = Encoder::new(ecc_len);
+    let decoder = Decoder::new(ecc_len);
 
-    thread::sleep(Duration::from_secs(1));
+    let buffer: Vec<u8> = Generator::new().take(data_len).collect();
+    let encoded = encoder.encode(&buffer);
+    let codewords: [Vec<u8>; LANES] = core::array::from_fn(|_| encoded.to_vec());
 
-    tx.send(()).unwrap();
-    let bytes = rx.recv().unwrap();
-    let kbytes = (bytes / 1024) as f32;
-    kbytes / 1024.0
-} 
+    measure(move || {
+        assert!(!decoder.is_corrupted_batch(&codewords).iter().any(|&x| x));
+        data_len * LANES
+    })
+}
 
-#[derive(RustcEncodable)]
 struct BenchResult {
     data_len: usize,
     ecc_len: usize,
     encoder: EncoderResult,
-    decoder: Vec<DecoderResult>
+    decoder: Vec<DecoderResult>,
+    batch_check: CheckResult
 } 
 
-#[derive(RustcEncodable)]
 struct EncoderResult {
-    bandwidth: f32
+    bandwidth: f32,
+    table_bandwidth: f32,
+    batch_bandwidth: f32
 }
 
-#[derive(RustcEncodable)]
 struct DecoderResult {
     errors: usize,
     bandwidth: f32
 }
 
+struct CheckResult {
+    lanes: usize,
+    bandwidth: f32
+}
+
+// Same as `#[derive(RustcEncodable)]`, which current compilers no longer provide
+impl Encodable for BenchResult {
+    fn encode<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.emit_struct("BenchResult", 5, |s| {
+            s.emit_struct_field("data_len", 0, |s| self.data_len.encode(s))?;
+            s.emit_struct_field("ecc_len", 1, |s| self.ecc_len.encode(s))?;
+            s.emit_struct_field("encoder", 2, |s| self.encoder.encode(s))?;
+            s.emit_struct_field("decoder", 3, |s| self.decoder.encode(s))?;
+            s.emit_struct_field("batch_check", 4, |s| self.batch_check.encode(s))
+        })
+    }
+}
+
+impl Encodable for EncoderResult {
+    fn encode<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.emit_struct("EncoderResult", 3, |s| {
+            s.emit_struct_field("bandwidth", 0, |s| self.bandwidth.encode(s))?;
+            s.emit_struct_field("table_bandwidth", 1, |s| self.table_bandwidth.encode(s))?;
+            s.emit_struct_field("batch_bandwidth", 2, |s| self.batch_bandwidth.encode(s))
+        })
+    }
+}
+
+impl Encodable for DecoderResult {
+    fn encode<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.emit_struct("DecoderResult", 2, |s| {
+            s.emit_struct_field("errors", 0, |s| self.errors.encode(s))?;
+            s.emit_struct_field("bandwidth", 1, |s| self.bandwidth.encode(s))
+        })
+    }
+}
+
+impl Encodable for CheckResult {
+    fn encode<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.emit_struct("CheckResult", 2, |s| {
+            s.emit_struct_field("lanes", 0, |s| self.lanes.encode(s))?;
+            s.emit_struct_field("bandwidth", 1, |s| self.bandwidth.encode(s))
+        })
+    }
+}
+
 fn main() {
     let results: Vec<BenchResult> = [(251, 4), (239, 16), (223, 32)].iter().map(|case| {
         let data_len = case.0;
         let ecc_len = case.1;
 
         BenchResult {
-            data_len: data_len,
-            ecc_len: ecc_len,
+            data_len,
+            ecc_len,
             encoder: EncoderResult {
                 bandwidth: encoder_bandwidth(data_len, ecc_len),
+                table_bandwidth: table_encoder_bandwidth(data_len, ecc_len),
+                batch_bandwidth: batch_encoder_bandwidth(data_len, ecc_len),
             },
             decoder: (0..(ecc_len / 2) + 1).map(|e| DecoderResult {
                 errors: e,
                 bandwidth: decoder_bandwidth(data_len, ecc_len, e)
-            }).collect()
+            }).collect(),
+            batch_check: CheckResult {
+                lanes: LANES,
+                bandwidth: batch_check_bandwidth(data_len, ecc_len),
+            }
         }
     }).collect();
 
@@ -0,0 +1,37 @@
+/// Columns of `L` byte codewords processed in lockstep
+///
+/// Column `j` holds symbol `j` of every codeword, one lane per codeword, so multiplying
+/// a column by a constant is a single `Field::mul_add_slice` over `L` lanes.
+/// Codewords are aligned at their ends: shorter ones are padded with leading zeros,
+/// which change neither their parity nor their syndromes.
+///
+/// `gather` reads one byte per codeword and branches on the padding, it's not a transpose.
+/// Batches are faster than single codewords only because every multiplication by a constant
+/// runs once over `L` lanes in the SIMD kernels of `mul_add_slice`, not because of the layout.
+pub struct Columns<'a, const L: usize> {
+    codewords: [&'a [u8]; L],
+    longest: usize,
+}
+
+impl<'a, const L: usize> Columns<'a, L> {
+    pub fn new<T: AsRef<[u8]>>(codewords: &'a [T; L]) -> Self {
+        let codewords: [&[u8]; L] = core::array::from_fn(|l| codewords[l].as_ref());
+        let longest = codewords.iter().map(|c| c.len()).max().unwrap_or(0);
+        Columns { codewords, longest }
+    }
+
+    /// Length of the longest codeword, which is the number of columns
+    #[inline]
+    pub fn longest(&self) -> usize {
+        self.longest
+    }
+
+    /// Writes column `j` to `column`
+    #[inline]
+    pub fn gather(&self, j: usize, column: &mut [u8; L]) {
+        for (x, codeword) in column.iter_mut().zip(self.codewords.iter()) {
+            let pad = self.longest - codeword.len();
+            *x = if j >= pad { codeword[j - pad] } else { 0 };
+        }
+    }
+}
@@ -1,4 +1,6 @@
 use ::gf::poly::Polynom;
+use ::gf::{Field, Gf2_8};
+use core::fmt;
 use core::ops::{Deref, DerefMut};
 
 /// Buffer for block encoded data
@@ -6,45 +8,44 @@ use core::ops::{Deref, DerefMut};
 /// ```rust
 /// use reed_solomon::Buffer;
 ///
-/// let buffer = Buffer::from_slice(&[1, 2, 3, 4], 2);
+/// let buffer: Buffer = Buffer::from_slice(&[1, 2, 3, 4], 2);
 /// assert_eq!(&[1, 2], buffer.data());
 /// assert_eq!(&[3, 4], buffer.ecc());
 /// ```
-#[derive(Debug, Copy, Clone)]
-pub struct Buffer {
-    poly: Polynom,
+pub struct Buffer<F: Field = Gf2_8> {
+    poly: Polynom<F>,
     data_len: usize,
 }
 
-impl Buffer {
+impl<F: Field> Buffer<F> {
     /// Create buffer from internal polynom
-    pub fn from_polynom(poly: Polynom, data_len: usize) -> Self {
+    pub fn from_polynom(poly: Polynom<F>, data_len: usize) -> Self {
         Buffer {
-            poly: poly,
-            data_len: data_len,
+            poly,
+            data_len,
         }
     }
 
-    /// Create buffer from [u8] slice
-    pub fn from_slice(slice: &[u8], data_len: usize) -> Self {
+    /// Create buffer from symbol slice
+    pub fn from_slice(slice: &[F::Elem], data_len: usize) -> Self {
         Buffer {
             poly: Polynom::from(slice),
-            data_len: data_len,
+            data_len,
         }
     }
 
     /// Slice with data of encoded block
-    pub fn data(&self) -> &[u8] {
+    pub fn data(&self) -> &[F::Elem] {
         &self[..self.data_len]
     }
 
     /// Slice with error correction core of encoced block
-    pub fn ecc(&self) -> &[u8] {
+    pub fn ecc(&self) -> &[F::Elem] {
         &self[self.data_len..]
     }
 
-    /// Add byte string to the end of buffer
-    pub fn append(&mut self, rhs: &[u8]) {
+    /// Add symbol string to the end of buffer
+    pub fn append(&mut self, rhs: &[F::Elem]) {
         let ofst = self.len();
         self.set_length(ofst + rhs.len());
         for (i, rhs_x) in rhs.iter().enumerate() {
@@ -53,21 +54,41 @@ impl Buffer {
     }
 }
 
-impl Deref for Buffer {
-    type Target = Polynom;
+impl<F: Field> Copy for Buffer<F> where F::Array: Copy {}
+
+impl<F: Field> Clone for Buffer<F> {
+    fn clone(&self) -> Self {
+        Buffer {
+            poly: self.poly.clone(),
+            data_len: self.data_len,
+        }
+    }
+}
+
+impl<F: Field> fmt::Debug for Buffer<F> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("Buffer")
+            .field("poly", &self.poly)
+            .field("data_len", &self.data_len)
+            .finish()
+    }
+}
+
+impl<F: Field> Deref for Buffer<F> {
+    type Target = Polynom<F>;
     fn deref(&self) -> &Self::Target {
         &self.poly
     }
 }
 
-impl DerefMut for Buffer {
+impl<F: Field> DerefMut for Buffer<F> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.poly
     }
 }
 
-impl From<Polynom> for Buffer {
-    fn from(p: Polynom) -> Buffer {
+impl<F: Field> From<Polynom<F>> for Buffer<F> {
+    fn from(p: Polynom<F>) -> Buffer<F> {
         Buffer {
             data_len: p.len(),
             poly: p,
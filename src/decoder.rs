@@ -1,26 +1,40 @@
 use core;
 use ::gf::poly_math::*;
 use ::gf::poly::Polynom;
+use ::gf::{Field, ConstantTime, Symbol, Gf2_8};
 use ::buffer::Buffer;
-use ::gf;
 
 /// Decoder error
 #[derive(Debug, Copy, Clone)]
 pub enum DecoderError {
     /// Message is unrecoverably corrupted
     TooManyErrors,
+    /// Message contains a symbol that doesn't belong to the field
+    InvalidSymbol,
+    /// Message is not shorter than the field order
+    /// or doesn't fit into polynomial capacity of the field
+    TooLong,
+    /// Message is shorter than the number of ecc symbols
+    TooShort,
+    /// Erasure position is out of the message
+    InvalidErasure,
 }
 
 type Result<T> = core::result::Result<T, DecoderError>;
 
 /// Reed-Solomon BCH decoder
 #[derive(Debug, Copy, Clone)]
-pub struct Decoder {
+pub struct Decoder<F: Field = Gf2_8> {
     ecc_len: usize,
+    /// Exponent of the first root of the generator polynomial
+    fcr: usize,
+    /// Primitive element raised to the root spacing, locators are its powers
+    base: F::Elem,
+    field: F,
 }
 
 impl Decoder {
-    /// Constructs a new `Decoder`.
+    /// Constructs a new `Decoder` over the default GF(2^8) field.
     ///
     /// # Example
     /// ```rust
@@ -29,7 +43,56 @@ impl Decoder {
     /// let decoder = Decoder::new(8);
     /// ```
     pub fn new(ecc_len: usize) -> Self {
-        Decoder { ecc_len: ecc_len }
+        Decoder::with_field(Gf2_8, ecc_len)
+    }
+}
+
+impl<F: Field> Decoder<F> {
+    /// Constructs a new `Decoder` over given `field`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::{Decoder, Gf2_8};
+    ///
+    /// let decoder = Decoder::with_field(Gf2_8, 8);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `ecc_len` is not less than `Polynom::capacity()` of the field,
+    /// e.g. 16 for `Capacity<Gf2_8, 16>`.
+    pub fn with_field(field: F, ecc_len: usize) -> Self {
+        Decoder::with_roots(field, ecc_len, 0, 1)
+    }
+
+    /// Constructs a new `Decoder` over given `field` for codewords of an `Encoder`
+    /// constructed by `Encoder::with_roots` with the same arguments.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::{Encoder, Decoder, BinaryField};
+    ///
+    /// // Data Matrix ECC 200: 0x12d field, roots a^1 ... a^ecc_len
+    /// let field = BinaryField::new(0x12d, 2);
+    /// let encoder = Encoder::with_roots(field.clone(), 5, 1, 1);
+    /// let decoder = Decoder::with_roots(field, 5, 1, 1);
+    ///
+    /// // "123456"
+    /// let mut encoded = encoder.encode(&[142, 164, 186]);
+    /// assert_eq!(&[114, 25, 5, 88, 102], encoded.ecc());
+    ///
+    /// encoded[0] = 0;
+    /// encoded[1] = 0;
+    /// assert_eq!(&[142, 164, 186], decoder.correct(&encoded, Some(&[1])).unwrap().data());
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `prim` is zero or shares a factor with `order - 1`,
+    /// or if `ecc_len` is not less than `Polynom::capacity()` of the field.
+    pub fn with_roots(field: F, ecc_len: usize, fcr: usize, prim: usize) -> Self {
+        assert!(ecc_len < Polynom::<F>::capacity(),
+                "ecc_len must be less than polynomial capacity of the field");
+        let base = ::gf::root_base(&field, prim);
+        Decoder { ecc_len, fcr, base, field }
     }
 
     /// Decodes block-encoded message and returns `Buffer` with corrected message and ecc offset.
@@ -61,16 +124,21 @@ impl Decoder {
     /// assert_eq!(&[1, 2, 3, 4], corrected.data())
     /// ```
     pub fn correct_err_count(&self,
-                             msg: &[u8],
-                             erase_pos: Option<&[u8]>)
-                             -> Result<(Buffer, usize)> {
-       let mut msg = Buffer::from_slice(msg, msg.len() - self.ecc_len);
+                             msg: &[F::Elem],
+                             erase_pos: Option<&[F::Elem]>)
+                             -> Result<(Buffer<F>, usize)> {
+        if msg.iter().any(|&x| !self.field.contains(x)) {
+            return Err(DecoderError::InvalidSymbol);
+        }
+        if msg.len() >= self.field.order() || msg.len() > Polynom::<F>::capacity() {
+            return Err(DecoderError::TooLong);
+        }
 
-        assert!(msg.len() < 256);
+        let mut msg = Buffer::from_slice(msg, msg.len() - self.ecc_len);
 
         let erase_pos = if let Some(erase_pos) = erase_pos {
             for e_pos in erase_pos {
-                msg[*e_pos as usize] = 0;
+                msg[e_pos.to_usize()] = F::Elem::ZERO;
             }
             erase_pos
         } else {
@@ -84,13 +152,13 @@ impl Decoder {
         let synd = self.calc_syndromes(&msg);
 
         // No errors
-        if synd.iter().all(|x| *x == 0) {
-            return Ok((msg,0));
+        if synd.iter().all(|x| *x == F::Elem::ZERO) {
+            return Ok((msg, 0));
         }
 
         let fsynd = self.forney_syndromes(&synd, erase_pos, msg.len());
-        let err_loc = try!(self.find_error_locator(&fsynd, None, erase_pos.len()));
-        let mut err_pos = try!(self.find_errors(&err_loc.reverse(), msg.len()));
+        let err_loc = self.find_error_locator(&fsynd, None, erase_pos.len())?;
+        let mut err_pos = self.find_errors(&err_loc.reverse(), msg.len())?;
 
         // Append erase_pos to err_pos
         for x in erase_pos.iter() {
@@ -135,13 +203,14 @@ impl Decoder {
     /// assert_eq!(&[1, 2, 3, 4], corrected.data())
     /// ```
     pub fn correct(&self,
-                   msg: &[u8],
-                   erase_pos: Option<&[u8]>)
-                   -> Result<Buffer> {
+                   msg: &[F::Elem],
+                   erase_pos: Option<&[F::Elem]>)
+                   -> Result<Buffer<F>> {
         self.correct_err_count(msg, erase_pos).map(|(r,_)| r)
      }
 
     /// Performs fast corruption check.
+    /// Messages containing symbols that don't belong to the field are considered corrupted.
     ///
     /// # Example
     /// ```rust
@@ -163,104 +232,161 @@ impl Decoder {
     ///
     /// assert_eq!(decoder.is_corrupted(&encoded), true);
     /// ```
-    pub fn is_corrupted(&self, msg: &[u8]) -> bool {
-        (0..self.ecc_len).any(|x| msg.eval(gf::pow(2, x as i32)) != 0)
+    pub fn is_corrupted(&self, msg: &[F::Elem]) -> bool {
+        msg.iter().any(|&x| !self.field.contains(x)) ||
+        (0..self.ecc_len).any(|i| self.syndrome(msg, i) != F::Elem::ZERO)
+    }
+
+    /// Locator of the symbol `power` positions from the end of a message
+    #[inline]
+    fn locator(&self, power: usize) -> F::Elem {
+        self.field.pow(self.base, power as i32)
+    }
+
+    /// Root `i` of the generator polynomial
+    #[inline]
+    fn root(&self, i: usize) -> F::Elem {
+        self.locator(self.fcr + i)
     }
 
-    fn calc_syndromes(&self, msg: &[u8]) -> Polynom {
+    /// Power of an error locator in the Forney algorithm, `1 - fcr`
+    #[inline]
+    fn forney_power(&self) -> i32 {
+        1 - self.fcr as i32
+    }
+
+    fn calc_syndromes(&self, msg: &[F::Elem]) -> Polynom<F> {
         // index 0 is a pad for mathematical precision
         let mut synd = Polynom::with_length(self.ecc_len + 1);
         for i in 0..self.ecc_len {
-            uncheck_mut!(synd[i + 1]) = msg.eval(gf::pow(2, i as i32))
+            uncheck_mut!(synd[i + 1]) = self.syndrome(msg, i);
         }
 
         synd
     }
 
-    fn find_errata_locator(&self, e_pos: &[u8]) -> Polynom {
-        let mut e_loc = polynom![1];
+    /// Value of `msg` at root `i` of the generator polynomial.
+    ///
+    /// Horner's method runs over blocks of the message, so that all symbols of a block
+    /// are multiplied by the same power of `alpha` at once, with `Field::mul_add_slice`.
+    fn syndrome(&self, msg: &[F::Elem], i: usize) -> F::Elem {
+        const BLOCK: usize = 32;
+
+        let field = &self.field;
+        let x = self.root(i);
+        if msg.len() < 2 * BLOCK {
+            return if msg.is_empty() { F::Elem::ZERO } else { msg.eval(x, field) };
+        }
 
-        let add_lhs = [1];
-        let mut add_rhs = [0, 0];
+        let x_block = field.pow(x, BLOCK as i32);
+        let mut acc = [F::Elem::ZERO; BLOCK];
+        let mut blocks = msg.chunks_exact(BLOCK);
+        for block in blocks.by_ref() {
+            let mut next = [F::Elem::ZERO; BLOCK];
+            next.copy_from_slice(block);
+            field.mul_add_slice(x_block, &acc, &mut next);
+            acc = next;
+        }
+
+        let mut y = acc.eval(x, field);
+        for px in blocks.remainder() {
+            y = field.add(field.mul(y, x), *px);
+        }
+        y
+    }
+
+    fn find_errata_locator(&self, e_pos: &[F::Elem]) -> Polynom<F> {
+        let mut e_loc = polynom![F::Elem::ONE];
+
+        let add_lhs = [F::Elem::ONE];
+        let mut add_rhs = [F::Elem::ZERO, F::Elem::ZERO];
         for i in e_pos.iter() {
-            add_rhs[0] = gf::pow(2, *i as i32);
-            e_loc = e_loc.mul(&add_lhs.add(&add_rhs));
+            add_rhs[0] = self.field.neg(self.locator(i.to_usize()));
+            e_loc = e_loc.mul(&add_lhs.add(&add_rhs, &self.field), &self.field);
         }
 
         e_loc
     }
 
-    fn find_error_evaluator(&self, synd: &[u8], err_loc: &[u8], syms: usize) -> Polynom {
-        let mut divisor = Polynom::with_length(syms + 2);
-        divisor[0] = 1;
+    fn find_error_evaluator(&self, synd: &[F::Elem], err_loc: &[F::Elem], syms: usize) -> Polynom<F> {
+        // Remainder of synd * err_loc divided by x^(syms + 1) is the lowest syms + 1
+        // coefficients of the product, so the whole product, which may not fit
+        // into a polynomial, is never built
+        let prod_len = synd.len() + err_loc.len() - 1;
+        let len = core::cmp::min(prod_len, syms + 1);
+        let skip = prod_len - len;
+
+        let mut remainder = Polynom::<F>::with_length(len);
+        for (i, s) in synd.iter().enumerate() {
+            for (j, e) in err_loc.iter().enumerate().skip(skip.saturating_sub(i)) {
+                let k = i + j - skip;
+                remainder[k] = self.field.add(remainder[k], self.field.mul(*s, *e));
+            }
+        }
 
-        let (_, remainder) = (synd.mul(err_loc)).div(&divisor);
         remainder
     }
 
     /// Forney algorithm, computes the values (error magnitude) to correct the input message.
     #[allow(non_snake_case)]
-    fn correct_errata(&self, msg: &[u8], synd: &[u8], err_pos: &[u8]) -> (Polynom, usize) {
+    fn correct_errata(&self, msg: &[F::Elem], synd: &[F::Elem], err_pos: &[F::Elem]) -> (Polynom<F>, usize) {
+        let field = &self.field;
+
         // convert the positions to coefficients degrees
-        let mut coef_pos = Polynom::with_length(err_pos.len());
+        let mut coef_pos = Polynom::<F>::with_length(err_pos.len());
         for (i, x) in err_pos.iter().enumerate() {
-            coef_pos[i] = msg.len() as u8 - 1 - x;
+            coef_pos[i] = F::Elem::from_usize(msg.len() - 1 - x.to_usize());
         }
 
         let err_loc = self.find_errata_locator(&coef_pos);
-        let synd = Polynom::from(synd);
+        let synd = Polynom::<F>::from(synd);
         let err_eval = self.find_error_evaluator(&synd.reverse(), &err_loc, err_loc.len() - 1)
             .reverse();
 
-        let mut X = Polynom::new();
+        let mut X = Polynom::<F>::new();
 
         for px in coef_pos.iter() {
-            let l = (255 - px) as i32;
-            X.push(gf::pow(2, -l))
+            X.push(self.locator(px.to_usize()))
         }
 
-        let mut E = Polynom::with_length(msg.len());
+        let mut E = Polynom::<F>::with_length(msg.len());
         let mut fixed = 0;
 
         let err_eval_rev = err_eval.reverse();
+        let err_loc_derivative = err_loc.derivative(field);
         for (i, Xi) in X.iter().enumerate() {
-            let Xi_inv = gf::inverse(*Xi);
-
-            let mut err_loc_prime_tmp = Polynom::new();
-            for (j, Xj) in X.iter().enumerate() {
-                if j != i {
-                    err_loc_prime_tmp.push(gf::sub(1, gf::mul(Xi_inv, *Xj)));
-                }
-            }
+            let Xi_inv = field.inverse(*Xi);
 
-            let mut err_loc_prime = 1;
-            for coef in err_loc_prime_tmp.iter() {
-                err_loc_prime = gf::mul(err_loc_prime, *coef);
-            }
+            // err_loc is the product of (1 - Xj x), so its derivative at Xi^-1
+            // is -Xi times the product of (1 - Xj / Xi) for j != i
+            let err_loc_prime = field.neg(field.div(err_loc_derivative.eval(Xi_inv, field), *Xi));
 
-            let y = err_eval_rev.eval(Xi_inv);
-            let y = gf::mul(gf::pow(*Xi, 1), y);
+            let y = err_eval_rev.eval(Xi_inv, field);
+            let y = field.mul(field.pow(*Xi, self.forney_power()), y);
 
-            let magnitude = gf::div(y, err_loc_prime);
+            let magnitude = field.div(y, err_loc_prime);
 
-            let E_index = uncheck!(err_pos[i]) as usize;
-            uncheck_mut!(E[E_index]) = magnitude;
+            // Magnitude is the error value added to the message, so subtract it
+            let E_index = uncheck!(err_pos[i]).to_usize();
+            uncheck_mut!(E[E_index]) = field.neg(magnitude);
             fixed += 1;
         }
 
-        (msg.add(&E), fixed)
+        (msg.add(&E, field), fixed)
     }
 
     #[allow(non_snake_case)]
     fn find_error_locator(&self,
-                          synd: &[u8],
-                          erase_loc: Option<&[u8]>,
+                          synd: &[F::Elem],
+                          erase_loc: Option<&[F::Elem]>,
                           erase_count: usize)
-                          -> Result<Polynom> {
+                          -> Result<Polynom<F>> {
+        let field = &self.field;
+
         let (mut err_loc, mut old_loc) = if let Some(erase_loc) = erase_loc {
             (Polynom::from(erase_loc), Polynom::from(erase_loc))
         } else {
-            (polynom![1], polynom![1])
+            (polynom![F::Elem::ONE], polynom![F::Elem::ONE])
         };
 
         let synd_shift = if synd.len() > self.ecc_len {
@@ -279,23 +405,22 @@ impl Decoder {
             let mut delta = uncheck!(synd[K]);
             for j in 1..err_loc.len() {
                 let d_index = err_loc.len() - j - 1;
-                delta ^= gf::mul(err_loc[d_index], uncheck!(synd[K - j]));
+                delta = field.add(delta, field.mul(err_loc[d_index], uncheck!(synd[K - j])));
             }
 
-            old_loc.push(0);
+            old_loc.push(F::Elem::ZERO);
 
-            if delta != 0 {
+            if delta != F::Elem::ZERO {
+                // err_loc - delta * old_loc
+                let new_loc = err_loc.add(&old_loc.scale(field.neg(delta), field), field);
                 if old_loc.len() > err_loc.len() {
-                    let new_loc = old_loc.scale(delta);
-                    old_loc = err_loc.scale(gf::inverse(delta));
-                    err_loc = new_loc;
+                    old_loc = err_loc.scale(field.inverse(delta), field);
                 }
-
-                err_loc = err_loc.add(&old_loc.scale(delta));
+                err_loc = new_loc;
             }
         }
 
-        let shift = err_loc.iter().take_while(|&&v| v == 0).count();
+        let shift = err_loc.iter().take_while(|&&v| v == F::Elem::ZERO).count();
         let err_loc = Polynom::from(&err_loc[shift..]);
 
         let errs = err_loc.len() - 1;
@@ -312,13 +437,13 @@ impl Decoder {
         }
     }
 
-    fn find_errors(&self, err_loc: &[u8], msg_len: usize) -> Result<Polynom> {
+    fn find_errors(&self, err_loc: &[F::Elem], msg_len: usize) -> Result<Polynom<F>> {
         let errs = err_loc.len() - 1;
         let mut err_pos = polynom![];
 
         for i in 0..msg_len {
-            if err_loc.eval(gf::pow(2, i as i32)) == 0 {
-                let x = msg_len as u8 - 1 - i as u8;
+            if err_loc.eval(self.locator(i), &self.field) == F::Elem::ZERO {
+                let x = F::Elem::from_usize(msg_len - 1 - i);
                 err_pos.push(x);
             }
         }
@@ -330,18 +455,18 @@ impl Decoder {
         }
     }
 
-    fn forney_syndromes(&self, synd: &[u8], pos: &[u8], msg_len: usize) -> Polynom {
-        let mut erase_pos_rev = Polynom::with_length(pos.len());
+    fn forney_syndromes(&self, synd: &[F::Elem], pos: &[F::Elem], msg_len: usize) -> Polynom<F> {
+        let mut erase_pos_rev = Polynom::<F>::with_length(pos.len());
         for (i, x) in pos.iter().enumerate() {
-            erase_pos_rev[i] = msg_len as u8 - 1 - x;
+            erase_pos_rev[i] = F::Elem::from_usize(msg_len - 1 - x.to_usize());
         }
 
         let mut fsynd = Polynom::from(&synd[1..]);
 
         for pos in erase_pos_rev.iter() {
-            let x = gf::pow(2, *pos as i32);
+            let x = self.locator(pos.to_usize());
             for j in 0..(fsynd.len() - 1) {
-                fsynd[j] = gf::mul(fsynd[j], x) ^ fsynd[j + 1];
+                fsynd[j] = self.field.sub(self.field.mul(fsynd[j], x), fsynd[j + 1]);
             }
         }
 
@@ -349,6 +474,223 @@ impl Decoder {
     }
 }
 
+impl<F: Field<Elem = u8>> Decoder<F> {
+    /// Checks `L` messages in lockstep, each same as `is_corrupted`.
+    ///
+    /// Symbols of all messages at the same position form a vector of `L` lanes, so Horner's
+    /// step of every syndrome covers all messages at once with `Field::mul_add_slice`.
+    /// 16, 32 or 64 lanes fill SSSE3 or AVX2 registers with `Gf2_8`.
+    /// Messages may have different lengths.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::{Encoder, Decoder};
+    ///
+    /// let encoder = Encoder::new(4);
+    /// let decoder = Decoder::new(4);
+    ///
+    /// let mut messages = [[0u8; 12]; 16];
+    /// for (i, message) in messages.iter_mut().enumerate() {
+    ///     message.copy_from_slice(&encoder.encode(&[i as u8; 8]));
+    /// }
+    /// messages[5][2] ^= 1;
+    ///
+    /// let corrupted = decoder.is_corrupted_batch(&messages);
+    /// assert_eq!(Some(5), corrupted.iter().position(|&x| x));
+    /// assert_eq!(1, corrupted.iter().filter(|&&x| x).count());
+    /// ```
+    pub fn is_corrupted_batch<T: AsRef<[u8]>, const L: usize>(&self, msgs: &[T; L]) -> [bool; L] {
+        const GROUP: usize = 32;
+
+        let mut corrupted: [bool; L] = core::array::from_fn(|l| {
+            msgs[l].as_ref().iter().any(|&x| !self.field.contains(x))
+        });
+
+        // Syndromes are computed a group at a time, to keep accumulators on the stack
+        let columns = ::batch::Columns::new(msgs);
+        let mut column = [0; L];
+        for start in (0..self.ecc_len).step_by(GROUP) {
+            let count = core::cmp::min(GROUP, self.ecc_len - start);
+            let mut x = [0; GROUP];
+            for (k, x) in x[..count].iter_mut().enumerate() {
+                *x = self.root(start + k);
+            }
+
+            let mut acc = [[0; L]; GROUP];
+            for j in 0..columns.longest() {
+                columns.gather(j, &mut column);
+                for (x, acc) in x[..count].iter().zip(acc.iter_mut()) {
+                    let mut next = column;
+                    self.field.mul_add_slice(*x, acc, &mut next);
+                    *acc = next;
+                }
+            }
+
+            for acc in acc[..count].iter() {
+                for (c, s) in corrupted.iter_mut().zip(acc.iter()) {
+                    *c |= *s != 0;
+                }
+            }
+        }
+
+        corrupted
+    }
+}
+
+impl<F: ConstantTime> Decoder<F> {
+    /// Decodes block-encoded message like `correct_err_count`, in time that depends only on
+    /// the message length, `ecc_len` and the erasure positions, and not on the number,
+    /// positions or values of the errors.
+    ///
+    /// Error locator is found with Berlekamp-Massey iterations that update every coefficient
+    /// and select the result with field arithmetic instead of branching, and every position of
+    /// the message is evaluated and corrected, with a zero correction where there is no error.
+    /// The only data-dependent branch is whether decoding succeeded.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::{Encoder, Decoder, Gf2_8ConstantTime};
+    ///
+    /// let encoder = Encoder::with_field(Gf2_8ConstantTime, 4);
+    /// let decoder = Decoder::with_field(Gf2_8ConstantTime, 4);
+    ///
+    /// let mut encoded = encoder.encode(&[1, 2, 3, 4]);
+    /// encoded[0] = 0;
+    /// encoded[3] = 0;
+    ///
+    /// let (corrected, fixed) = decoder.correct_constant_time(&encoded, Some(&[3])).unwrap();
+    /// assert_eq!(&[1, 2, 3, 4], corrected.data());
+    /// assert_eq!(2, fixed);
+    /// ```
+    #[allow(non_snake_case)]
+    pub fn correct_constant_time(&self,
+                                 msg: &[F::Elem],
+                                 erase_pos: Option<&[F::Elem]>)
+                                 -> Result<(Buffer<F>, usize)> {
+        let field = &self.field;
+        let ecc_len = self.ecc_len;
+
+        if msg.iter().fold(false, |invalid, &x| invalid | !field.contains(x)) {
+            return Err(DecoderError::InvalidSymbol);
+        }
+        if msg.len() >= field.order() || msg.len() > Polynom::<F>::capacity() {
+            return Err(DecoderError::TooLong);
+        }
+
+        let mut msg = Buffer::from_slice(msg, msg.len() - ecc_len);
+        let n = msg.len();
+
+        let erase_pos = erase_pos.unwrap_or(&[]);
+        if erase_pos.len() > ecc_len {
+            return Err(DecoderError::TooManyErrors);
+        }
+
+        // Polynomials below are stored lowest degree first
+
+        // Erasure locator, product of (1 - Y x) for every erasure locator Y
+        let mut Lambda = Polynom::<F>::with_length(ecc_len + 1);
+        Lambda[0] = F::Elem::ONE;
+        for pos in erase_pos {
+            msg[pos.to_usize()] = F::Elem::ZERO;
+            let Y = self.locator(n - 1 - pos.to_usize());
+            for i in (1..ecc_len + 1).rev() {
+                Lambda[i] = field.sub(Lambda[i], field.mul(Y, Lambda[i - 1]));
+            }
+        }
+
+        // Syndromes of the message with erasures zeroed
+        let mut S = Polynom::<F>::with_length(ecc_len);
+        for (j, s) in S.iter_mut().enumerate() {
+            *s = msg.eval(self.root(j), field);
+        }
+
+        let rho = erase_pos.len();
+        let mut B = Lambda.clone();
+        let mut L = rho;
+        for r in rho..ecc_len {
+            let mut delta = F::Elem::ZERO;
+            for i in 0..r + 1 {
+                delta = field.add(delta, field.mul(Lambda[i], S[r - i]));
+            }
+
+            // Length changes if delta != 0 and 2L <= r + rho
+            let shorter = (2 * L).wrapping_sub(r + rho + 1) >> (usize::BITS - 1);
+            let change = is_non_zero(field, delta).to_usize() & shorter;
+            let change_elem = F::Elem::from_usize(change);
+            let delta_inv = field.inverse(delta);
+
+            for i in (0..ecc_len + 1).rev() {
+                let xB = if i == 0 { F::Elem::ZERO } else { B[i - 1] };
+                let scaled = field.mul(delta_inv, Lambda[i]);
+                Lambda[i] = field.sub(Lambda[i], field.mul(delta, xB));
+                B[i] = select(field, change_elem, scaled, xB);
+            }
+
+            let mask = 0usize.wrapping_sub(change);
+            L = (L & !mask) | ((r + 1 + rho).wrapping_sub(L) & mask);
+        }
+
+        // Error evaluator S * Lambda mod x^ecc_len, and formal derivative of Lambda
+        let mut Omega = Polynom::<F>::with_length(ecc_len);
+        for k in 0..ecc_len {
+            for i in 0..k + 1 {
+                Omega[k] = field.add(Omega[k], field.mul(Lambda[i], S[k - i]));
+            }
+        }
+
+        let mut Lambda_prime = Polynom::<F>::with_length(ecc_len);
+        let mut degree = F::Elem::ZERO;
+        for i in 0..ecc_len {
+            degree = field.add(degree, F::Elem::ONE);
+            Lambda_prime[i] = field.mul(degree, Lambda[i + 1]);
+        }
+
+        // Chien search and Forney algorithm over every position
+        let mut fixed = 0;
+        for p in 0..n {
+            let k = n - 1 - p;
+            let X = self.locator(k);
+            let X_inv = field.inverse(X);
+
+            let root = field.sub(F::Elem::ONE, is_non_zero(field, eval_lowest_first(&Lambda, X_inv, field)));
+            let y = field.mul(field.pow(X, self.forney_power()), eval_lowest_first(&Omega, X_inv, field));
+            let magnitude = field.div(y, eval_lowest_first(&Lambda_prime, X_inv, field));
+
+            // Error value is -magnitude, subtract it where Lambda has a root
+            msg[p] = field.add(msg[p], field.mul(root, magnitude));
+            fixed += root.to_usize();
+        }
+
+        let corrupted = (0..ecc_len).fold(false, |corrupted, j| {
+            corrupted | (msg.eval(self.root(j), field) != F::Elem::ZERO)
+        });
+
+        if corrupted | (fixed != L) | (2 * L > ecc_len + rho) {
+            Err(DecoderError::TooManyErrors)
+        } else {
+            Ok((msg, fixed))
+        }
+    }
+}
+
+/// One if `x` is non-zero, zero otherwise, relies on `inverse(0) == 0`
+#[inline]
+fn is_non_zero<F: ConstantTime>(field: &F, x: F::Elem) -> F::Elem {
+    field.mul(x, field.inverse(x))
+}
+
+/// `a` if `cond` is one, `b` if `cond` is zero
+#[inline]
+fn select<F: ConstantTime>(field: &F, cond: F::Elem, a: F::Elem, b: F::Elem) -> F::Elem {
+    field.add(b, field.mul(cond, field.sub(a, b)))
+}
+
+/// Horner's method for polynomials stored lowest degree first
+#[inline]
+fn eval_lowest_first<F: Field>(poly: &[F::Elem], x: F::Elem, field: &F) -> F::Elem {
+    poly.iter().rev().fold(F::Elem::ZERO, |acc, &c| field.add(field.mul(acc, x), c))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -368,6 +710,28 @@ mod tests {
     }
 
     #[test]
+    fn syndrome_blocks() {
+        use ::gf::PrimeField;
+        use std::vec::Vec;
+
+        let msg: Vec<u8> = (0..300).map(|x: u32| (x * 97 + 5) as u8).collect();
+        let decoder = Decoder::new(8);
+        for len in 1..msg.len() {
+            for i in 0..8 {
+                assert_eq!(msg[..len].eval(decoder.root(i), &Gf2_8), decoder.syndrome(&msg[..len], i));
+            }
+        }
+
+        let field = PrimeField::new(929, 3);
+        let msg: Vec<u32> = (0..200).map(|x| x * 31 % 929).collect();
+        let decoder = Decoder::with_field(field, 4);
+        for len in 1..msg.len() {
+            assert_eq!(msg[..len].eval(decoder.root(3), &field), decoder.syndrome(&msg[..len], 3));
+        }
+    }
+
+    #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn is_corrupted() {
         let px = [1, 2, 3, 4, 5, 6, 7, 8, 9];
         let mut encoded = Encoder::new(8).encode(&px[..]);
@@ -376,7 +740,36 @@ mod tests {
 
         encoded[5] = 1;
 
-        assert_eq!(true, Decoder::new(8).is_corrupted(&encoded));
+        assert!(Decoder::new(8).is_corrupted(&encoded));
+    }
+
+    #[test]
+    fn is_corrupted_batch() {
+        use std::vec::Vec;
+
+        let encoder = Encoder::new(40);
+        let decoder = Decoder::new(40);
+        let mut msgs: [Vec<u8>; 32] = core::array::from_fn(|l| {
+            let data: Vec<u8> = (0..200 - l).map(|i| (i * 3 + l) as u8).collect();
+            encoder.encode(&data).to_vec()
+        });
+        assert_eq!([false; 32], decoder.is_corrupted_batch(&msgs));
+
+        msgs[0][0] ^= 1;
+        msgs[7][100] ^= 0x80;
+        msgs[31][168] ^= 3;
+        let corrupted = decoder.is_corrupted_batch(&msgs);
+        for (l, msg) in msgs.iter().enumerate() {
+            assert_eq!(decoder.is_corrupted(msg), corrupted[l]);
+            assert_eq!(l == 0 || l == 7 || l == 31, corrupted[l]);
+        }
+
+        let field = ::gf::BinaryField::new(0x13, 2);
+        let decoder = Decoder::with_field(field.clone(), 4);
+        let encoded = Encoder::with_field(field, 4).encode(&[1, 2, 3]);
+        let mut invalid = encoded.to_vec();
+        invalid[0] = 16;
+        assert_eq!([false, true], decoder.is_corrupted_batch(&[encoded.to_vec(), invalid]));
     }
 
     #[test]
@@ -470,7 +863,7 @@ mod tests {
 
     #[test]
     fn decode() {
-        let mut msg = [0, 2, 2, 2, 2, 2, 119, 111, 114, 108, 100, 145, 124, 96, 105, 94, 31, 179, 149, 163];
+        let msg = [0, 2, 2, 2, 2, 2, 119, 111, 114, 108, 100, 145, 124, 96, 105, 94, 31, 179, 149, 163];
         let ecc = 9;
         let erase_pos = [0, 1, 2];
 
@@ -478,8 +871,122 @@ mod tests {
                       31, 179, 149, 163];
 
         let decoder = Decoder::new(ecc);
-        let decoded = decoder.correct(&mut msg[..], Some(&erase_pos)).unwrap();
+        let decoded = decoder.correct(&msg[..], Some(&erase_pos)).unwrap();
 
         assert_eq!(result, **decoded);
     }
+
+    #[test]
+    fn roots() {
+        use ::gf::{BinaryField, PrimeField, Gf2_8ConstantTime};
+
+        fn check<F: Field + Clone>(field: F, data: &[F::Elem], fcr: usize, prim: usize) {
+            let ecc_len = 8;
+            let encoded = Encoder::with_roots(field.clone(), ecc_len, fcr, prim).encode(data);
+            let decoder = Decoder::with_roots(field, ecc_len, fcr, prim);
+            assert_eq!([F::Elem::ZERO; 9], *decoder.calc_syndromes(&encoded));
+
+            for errors in 0..4 {
+                let erasures = ecc_len - 2 * errors;
+                let mut corrupted = encoded.clone();
+                // Errors at the end, erasures at the start
+                let len = corrupted.len();
+                for i in 0..errors {
+                    let p = len - 1 - 2 * i;
+                    corrupted[p] = decoder.field.add(corrupted[p], F::Elem::ONE);
+                }
+                let erase_pos: [F::Elem; 8] = core::array::from_fn(F::Elem::from_usize);
+                for pos in erase_pos[..erasures].iter() {
+                    corrupted[pos.to_usize()] = F::Elem::ZERO;
+                }
+
+                assert!(decoder.is_corrupted(&corrupted));
+                let decoded = decoder.correct(&corrupted, Some(&erase_pos[..erasures])).unwrap();
+                assert_eq!(&encoded[..], &decoded[..]);
+            }
+        }
+
+        let data: [u8; 40] = core::array::from_fn(|i| (i * 29 + 3) as u8);
+        check(Gf2_8, &data, 112, 11);
+        check(Gf2_8, &data, 1, 1);
+        check(Gf2_8, &data, 200, 7);
+        check(BinaryField::new(0x187, 2), &data, 112, 11);
+        check(BinaryField::new(0x13, 2), &[1, 2, 3, 15], 3, 2);
+        check(PrimeField::new(929, 3), &[5, 928, 17, 0, 300, 1], 1, 3);
+
+        // Syndromes are zero only at the configured roots
+        let encoded = Encoder::with_roots(Gf2_8, 4, 112, 11).encode(&data);
+        assert!(!Decoder::with_roots(Gf2_8, 4, 112, 11).is_corrupted(&encoded));
+        assert!(Decoder::new(4).is_corrupted(&encoded));
+        assert_eq!([false], Decoder::with_roots(Gf2_8, 4, 112, 11).is_corrupted_batch(&[&encoded[..]]));
+
+        let encoder = Encoder::with_roots(Gf2_8ConstantTime, 8, 112, 11);
+        let decoder = Decoder::with_roots(Gf2_8ConstantTime, 8, 112, 11);
+        let encoded = encoder.encode(&data);
+        let mut corrupted = encoded;
+        corrupted[3] ^= 0x40;
+        corrupted[30] ^= 0x01;
+        corrupted[17] = 0;
+        let (decoded, fixed) = decoder.correct_constant_time(&corrupted, Some(&[17, 5])).unwrap();
+        assert_eq!(&encoded[..], &decoded[..]);
+        assert_eq!(4, fixed);
+    }
+
+    #[test]
+    #[should_panic]
+    fn roots_spacing() {
+        // 3 divides 255
+        Decoder::with_roots(Gf2_8, 8, 0, 3);
+    }
+
+    #[test]
+    fn decode_constant_time() {
+        use ::gf::Gf2_8ConstantTime;
+
+        let msg = [0, 2, 2, 2, 2, 2, 119, 111, 114, 108, 100, 145, 124, 96, 105, 94, 31, 179, 149, 163];
+        let erase_pos = [0, 1, 2];
+        let result = [104, 101, 108, 108, 111, 32, 119, 111, 114, 108, 100, 145, 124, 96, 105, 94,
+                      31, 179, 149, 163];
+
+        let decoder = Decoder::with_field(Gf2_8ConstantTime, 9);
+        let (decoded, fixed) = decoder.correct_constant_time(&msg[..], Some(&erase_pos)).unwrap();
+        assert_eq!(result, **decoded);
+        assert_eq!(6, fixed);
+
+        // Every pattern within capacity is corrected the same way as by `correct_err_count`
+        let data: [u8; 24] = [
+            0x40, 0xd2, 0x75, 0x47, 0x76, 0x17, 0x32, 0x06, 0x27, 0x26, 0x96, 0xc6,
+            0xc6, 0x96, 0x70, 0xec, 0x11, 0xec, 0x11, 0xec, 0x11, 0xec, 0x11, 0xec,
+        ];
+        let ecc_len = 8;
+        let encoded = Encoder::new(ecc_len).encode(&data);
+        let decoder = Decoder::with_field(Gf2_8ConstantTime, ecc_len);
+        let reference = Decoder::new(ecc_len);
+
+        for errors in 0..5 {
+            for erasures in 0..(ecc_len - 2 * errors + 1) {
+                let mut corrupted = encoded;
+                for i in 0..errors {
+                    corrupted[i * 7 + 1] ^= 0x5a + i as u8;
+                }
+                let erase_pos: [u8; 8] = [3, 10, 11, 20, 25, 26, 30, 31];
+                let erase_pos = &erase_pos[..erasures];
+                for &pos in erase_pos {
+                    corrupted[pos as usize] ^= 0xff;
+                }
+
+                let (decoded, fixed) = decoder.correct_constant_time(&corrupted, Some(erase_pos)).unwrap();
+                let (expected, expected_fixed) = reference.correct_err_count(&corrupted, Some(erase_pos)).unwrap();
+                assert_eq!(&encoded[..], &decoded[..]);
+                assert_eq!(&expected[..], &decoded[..]);
+                assert_eq!(expected_fixed, fixed);
+            }
+        }
+
+        let mut corrupted = encoded;
+        for i in 0..5 {
+            corrupted[i * 3] ^= 0x33;
+        }
+        assert!(decoder.correct_constant_time(&corrupted, None).is_err());
+    }
 }
@@ -1,16 +1,35 @@
-use ::gf::poly_math::*;
 use ::gf::poly::Polynom;
+use ::gf::{Field, Symbol, Gf2_8};
 use ::buffer::Buffer;
-use ::gf;
+
+/// Encoder error
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EncoderError {
+    /// Data contains a symbol that doesn't belong to the field
+    InvalidSymbol,
+    /// Codeword doesn't fit into the field: it may be at most `order - 1` symbols long,
+    /// and `try_encode` also requires it to fit into polynomial capacity of the field
+    TooLong,
+    /// Codeword is shorter than the error correction code
+    TooShort,
+    /// Parity slice length differs from the error correction code length
+    ParityLength,
+}
+
+/// Longest ecc of fields with byte symbols, whose codewords are at most `order - 1 <= 255`
+/// symbols long
+const MAX_BYTE_ECC_LEN: usize = 255;
 
 /// Reed-Solomon BCH encoder
 #[derive(Debug)]
-pub struct Encoder {
-    generator: Polynom,
+pub struct Encoder<F: Field = Gf2_8> {
+    generator: Polynom<F>,
+    field: F,
 }
 
 impl Encoder {
-    /// Constructs a new `Encoder` and calculates generator polynomial of given `ecc_len`.
+    /// Constructs a new `Encoder` over the default GF(2^8) field
+    /// and calculates generator polynomial of given `ecc_len`.
     ///
     /// # Example
     /// ```rust
@@ -19,7 +38,60 @@ impl Encoder {
     /// let encoder = Encoder::new(8);
     /// ```
     pub fn new(ecc_len: usize) -> Self {
-        Encoder { generator: generator_poly(ecc_len) }
+        Encoder::with_field(Gf2_8, ecc_len)
+    }
+}
+
+impl<F: Field> Encoder<F> {
+    /// Constructs a new `Encoder` over given `field`
+    /// and calculates generator polynomial of given `ecc_len`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::{Encoder, Gf2_8};
+    ///
+    /// let encoder = Encoder::with_field(Gf2_8, 8);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `ecc_len` is not less than `Polynom::capacity()` of the field,
+    /// e.g. 16 for `Capacity<Gf2_8, 16>`.
+    pub fn with_field(field: F, ecc_len: usize) -> Self {
+        Encoder::with_roots(field, ecc_len, 0, 1)
+    }
+
+    /// Constructs a new `Encoder` over given `field`, whose generator polynomial has
+    /// `ecc_len` roots `a^fcr, a^(fcr + 1), ..., a^(fcr + ecc_len - 1)`, where `a` is
+    /// the primitive element raised to `prim`, the root spacing.
+    ///
+    /// `with_field` is the same with `fcr` 0 and `prim` 1. Codewords must be decoded
+    /// with a `Decoder` constructed with the same roots.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::{Encoder, Decoder, BinaryField};
+    ///
+    /// // CCSDS code over the 0x187 field, in conventional representation
+    /// let field = BinaryField::new(0x187, 2);
+    /// let encoder = Encoder::with_roots(field.clone(), 32, 112, 11);
+    /// let decoder = Decoder::with_roots(field, 32, 112, 11);
+    ///
+    /// let mut encoded = encoder.encode(&[7; 223]);
+    /// encoded[10] = 0;
+    /// assert_eq!(&[7; 223], decoder.correct(&encoded, None).unwrap().data());
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `prim` is zero or shares a factor with `order - 1`,
+    /// or if `ecc_len` is not less than `Polynom::capacity()` of the field.
+    pub fn with_roots(field: F, ecc_len: usize, fcr: usize, prim: usize) -> Self {
+        assert!(ecc_len < Polynom::<F>::capacity(),
+                "ecc_len must be less than polynomial capacity of the field");
+        let base = ::gf::root_base(&field, prim);
+        Encoder {
+            generator: generator_poly(ecc_len, fcr, base, &field),
+            field,
+        }
     }
 
     /// Encodes passed `&[u8]` slice and returns `Buffer` with result and `ecc` offset.
@@ -37,39 +109,487 @@ impl Encoder {
     /// println!("data:  {:?}", encoded.data());
     /// println!("ecc:   {:?}", encoded.ecc());
     /// ```
-    pub fn encode(&self, data: &[u8]) -> Buffer {
+    ///
+    /// # Panics
+    /// Panics if `data` is rejected by `try_encode`.
+    pub fn encode(&self, data: &[F::Elem]) -> Buffer<F> {
+        match self.try_encode(data) {
+            Ok(buffer) => buffer,
+            Err(EncoderError::InvalidSymbol) => panic!("data symbol doesn't belong to the field"),
+            Err(EncoderError::TooLong) => panic!("codeword is too long for the field"),
+            Err(EncoderError::TooShort) | Err(EncoderError::ParityLength) => unreachable!(),
+        }
+    }
+
+    /// Encodes passed slice and returns `Buffer` with result and `ecc` offset.
+    ///
+    /// Returns an error if some symbol of `data` is not an element of the field,
+    /// or if the codeword would be longer than `order - 1` symbols or than
+    /// `Polynom::capacity()` of the field, which holds the result.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::{Encoder, EncoderError, BinaryField};
+    ///
+    /// // GF(16)
+    /// let encoder = Encoder::with_field(BinaryField::new(0x13, 2), 4);
+    ///
+    /// assert!(encoder.try_encode(&[1, 2, 15]).is_ok());
+    /// assert_eq!(Some(EncoderError::InvalidSymbol), encoder.try_encode(&[1, 2, 16]).err());
+    /// assert_eq!(Some(EncoderError::TooLong), encoder.try_encode(&[0; 12]).err());
+    /// ```
+    pub fn try_encode(&self, data: &[F::Elem]) -> Result<Buffer<F>, EncoderError> {
+        // The rest is checked by `encode_in_place`
+        if data.len() + self.ecc_len() > Polynom::<F>::capacity() {
+            return Err(EncoderError::TooLong);
+        }
+
         let mut data_out = Polynom::from(data);
-        let data_len = data.len();
-
-        data_out.set_length(data_len + self.generator.len() - 1);
-
-        let gen = self.generator;
-        let mut lgen = Polynom::with_length(self.generator.len());
-        for (i, gen_i) in gen.iter().enumerate() {
-            uncheck_mut!(lgen[i]) = gf::LOG[*gen_i as usize];
-        } 
-        
-        for i in 0..data_len {
-            let coef = uncheck!(data_out[i]);
-            if coef != 0 {
-                let lcoef = gf::LOG[coef as usize] as usize;
-                for j in 1..gen.len() {
-                    uncheck_mut!(data_out[i + j]) ^= gf::EXP[(lcoef + lgen[j] as usize)];
+        data_out.set_length(data.len() + self.ecc_len());
+        self.encode_in_place(&mut data_out)?;
+
+        Ok(Buffer::from_polynom(data_out, data.len()))
+    }
+
+    /// Starts incremental encoding of data that arrives in chunks.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::Encoder;
+    ///
+    /// let encoder = Encoder::new(4);
+    ///
+    /// let mut state = encoder.start();
+    /// state.update(&[1, 2]).unwrap();
+    /// state.update(&[3, 4, 5]).unwrap();
+    ///
+    /// assert_eq!(encoder.encode(&[1, 2, 3, 4, 5]).ecc(), &state.finalize()[..]);
+    /// ```
+    pub fn start(&self) -> EncoderState<'_, F> {
+        EncoderState {
+            encoder: self,
+            remainder: Polynom::with_length(self.ecc_len()),
+            data_len: 0,
+        }
+    }
+
+    /// Encodes data in the beginning of `codeword` and writes `ecc` to its last `ecc_len` symbols,
+    /// without copying the data.
+    ///
+    /// Returns an error if `codeword` is shorter than `ecc_len`, if some data symbol is not
+    /// an element of the field, or if `codeword` is longer than `order - 1` symbols.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::{Encoder, EncoderError};
+    ///
+    /// let encoder = Encoder::new(4);
+    ///
+    /// // Data followed by space reserved for ecc
+    /// let mut codeword = [1, 2, 3, 4, 0, 0, 0, 0];
+    /// encoder.encode_in_place(&mut codeword).unwrap();
+    /// assert_eq!(&encoder.encode(&[1, 2, 3, 4])[..], &codeword[..]);
+    ///
+    /// assert_eq!(Some(EncoderError::TooShort), encoder.encode_in_place(&mut [0; 3]).err());
+    /// ```
+    pub fn encode_in_place(&self, codeword: &mut [F::Elem]) -> Result<(), EncoderError> {
+        let data_len = check_codeword(&self.field, codeword, self.ecc_len())?;
+        let (data, ecc) = codeword.split_at_mut(data_len);
+        self.parity(data, ecc);
+        Ok(())
+    }
+
+    /// Writes `ecc` of `data` to `parity_out`, which must be exactly `ecc_len` symbols long.
+    /// The codeword is `data` followed by `parity_out`, same as `encode` returns.
+    ///
+    /// Returns an error if `parity_out` has a different length, if some symbol of `data`
+    /// is not an element of the field, or if the codeword would be longer
+    /// than `order - 1` symbols.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::{Encoder, EncoderError};
+    ///
+    /// let encoder = Encoder::new(4);
+    ///
+    /// let data = [1, 2, 3, 4];
+    /// let mut parity = [0; 4];
+    /// encoder.compute_parity(&data, &mut parity).unwrap();
+    /// assert_eq!(encoder.encode(&data).ecc(), &parity[..]);
+    ///
+    /// assert_eq!(Some(EncoderError::ParityLength), encoder.compute_parity(&data, &mut [0; 5]).err());
+    /// ```
+    pub fn compute_parity(&self, data: &[F::Elem], parity_out: &mut [F::Elem]) -> Result<(), EncoderError> {
+        if parity_out.len() != self.ecc_len() {
+            return Err(EncoderError::ParityLength);
+        }
+
+        check_data(&self.field, data, parity_out.len())?;
+        self.parity(data, parity_out);
+        Ok(())
+    }
+
+    /// Number of ecc symbols
+    #[inline]
+    fn ecc_len(&self) -> usize {
+        self.generator.len() - 1
+    }
+
+    /// Writes `ecc` of already checked `data` to `parity_out`
+    fn parity(&self, data: &[F::Elem], parity_out: &mut [F::Elem]) {
+        self.remainder(data, parity_out);
+
+        // Codeword is data minus the remainder
+        for x in parity_out.iter_mut() {
+            *x = self.field.neg(*x);
+        }
+    }
+
+    /// Remainder of the division of `data * x^ecc_len` by the generator polynomial.
+    ///
+    /// `remainder` is a shift register of the division: its first symbol is the one
+    /// to be subtracted from the next data symbol.
+    fn remainder(&self, data: &[F::Elem], remainder: &mut [F::Elem]) {
+        for x in remainder.iter_mut() {
+            *x = F::Elem::ZERO;
+        }
+        self.divide(data, remainder);
+    }
+
+    /// Shifts `data` through the division register `remainder`
+    fn divide(&self, data: &[F::Elem], remainder: &mut [F::Elem]) {
+        if remainder.is_empty() {
+            return;
+        }
+
+        let gen = &self.generator[1..];
+        for x in data.iter() {
+            let coef = self.field.add(*x, remainder[0]);
+            remainder.copy_within(1.., 0);
+            let last = remainder.len() - 1;
+            remainder[last] = F::Elem::ZERO;
+
+            if coef != F::Elem::ZERO {
+                self.field.mul_add_slice(self.field.neg(coef), gen, remainder);
+            }
+        }
+    }
+}
+
+impl<F: Field<Elem = u8>> Encoder<F> {
+    /// Encodes `L` codewords in lockstep, each same as `encode_in_place`.
+    ///
+    /// Symbols of all codewords at the same position form a vector of `L` lanes, so every
+    /// generator coefficient multiplies all codewords at once with `Field::mul_add_slice`.
+    /// 16, 32 or 64 lanes fill SSSE3 or AVX2 registers with `Gf2_8`.
+    /// Codewords may have different lengths.
+    ///
+    /// Returns an error if any codeword is rejected by `encode_in_place`,
+    /// then no codeword is modified.
+    ///
+    /// Columns of symbols are gathered one byte per codeword, so the speedup over encoding
+    /// codewords one by one comes only from the SIMD kernels of `mul_add_slice`.
+    ///
+    /// The division register is sized for the longest ecc of byte fields whatever `ecc_len` is:
+    /// it always takes `255 * L` bytes of stack, e.g. 16 KiB for 64 lanes even with
+    /// `ecc_len` of 4.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::Encoder;
+    ///
+    /// let encoder = Encoder::new(4);
+    ///
+    /// let mut codewords = [[0u8; 12]; 16];
+    /// for (i, codeword) in codewords.iter_mut().enumerate() {
+    ///     codeword[..8].copy_from_slice(&[i as u8; 8]);
+    /// }
+    /// encoder.encode_batch(&mut codewords).unwrap();
+    /// assert_eq!(&encoder.encode(&[3; 8])[..], &codewords[3][..]);
+    /// ```
+    pub fn encode_batch<T, const L: usize>(&self, codewords: &mut [T; L]) -> Result<(), EncoderError>
+        where T: AsRef<[u8]> + AsMut<[u8]>
+    {
+        let ecc_len = self.ecc_len();
+        for codeword in codewords.iter() {
+            check_codeword(&self.field, codeword.as_ref(), ecc_len)?;
+        }
+
+        if ecc_len == 0 || L == 0 {
+            return Ok(());
+        }
+
+        // Register of the division holding the parity, its symbol `i` is
+        // `register[(head + i) % ecc_len]`, so shifting it moves no data.
+        // Checked codewords hold the ecc, so it fits
+        let mut register = [[0; L]; MAX_BYTE_ECC_LEN];
+        let mut head = 0;
+        let gen = &self.generator[1..];
+
+        {
+            let columns = ::batch::Columns::new(codewords);
+            let mut coef = [0; L];
+            for j in 0..columns.longest() - ecc_len {
+                columns.gather(j, &mut coef);
+                for (c, r) in coef.iter_mut().zip(register[head].iter_mut()) {
+                    *c = self.field.sub(*c, *r);
+                    *r = 0;
+                }
+
+                head = (head + 1) % ecc_len;
+                for (i, g) in gen.iter().enumerate() {
+                    self.field.mul_add_slice(*g, &coef, &mut register[(head + i) % ecc_len]);
                 }
             }
         }
 
-        data_out[..data_len].copy_from_slice(data);
-        Buffer::from_polynom(data_out, data_len)
+        for (l, codeword) in codewords.iter_mut().enumerate() {
+            let codeword = codeword.as_mut();
+            let data_len = codeword.len() - ecc_len;
+            for (i, x) in codeword[data_len..].iter_mut().enumerate() {
+                *x = register[(head + i) % ecc_len][l];
+            }
+        }
+
+        Ok(())
     }
 }
 
-fn generator_poly(ecclen: usize) -> Polynom {
-    let mut gen = polynom![1];
-    let mut mm = [1, 0];
+/// Incremental encoder, created by `Encoder::start`.
+///
+/// Holds the remainder of the data received so far, so chunks can be discarded
+/// once passed to `update`. The result is the same as of a single `encode` call
+/// with all chunks concatenated.
+#[derive(Debug)]
+pub struct EncoderState<'a, F: Field + 'a = Gf2_8> {
+    encoder: &'a Encoder<F>,
+    remainder: Polynom<F>,
+    data_len: usize,
+}
+
+impl<'a, F: Field> EncoderState<'a, F> {
+    /// Appends `chunk` to the encoded data.
+    ///
+    /// Returns an error and ignores the chunk if some of its symbols is not an element
+    /// of the field, or if the codeword would be longer than `order - 1` symbols.
+    pub fn update(&mut self, chunk: &[F::Elem]) -> Result<(), EncoderError> {
+        let encoder = self.encoder;
+        check_data(&encoder.field, chunk, self.data_len + self.remainder.len())?;
+
+        encoder.divide(chunk, &mut self.remainder);
+        self.data_len += chunk.len();
+        Ok(())
+    }
+
+    /// Number of data symbols passed to `update`
+    pub fn data_len(&self) -> usize {
+        self.data_len
+    }
+
+    /// Finishes encoding and returns `ecc` of the data
+    pub fn finalize(self) -> Polynom<F> {
+        let mut parity = self.remainder;
+        for x in parity.iter_mut() {
+            *x = self.encoder.field.neg(*x);
+        }
+        parity
+    }
+}
+
+impl<'a, F: Field> Clone for EncoderState<'a, F> {
+    fn clone(&self) -> Self {
+        EncoderState {
+            encoder: self.encoder,
+            remainder: self.remainder.clone(),
+            data_len: self.data_len,
+        }
+    }
+}
+
+/// Reed-Solomon BCH encoder with precomputed multiplication tables, for fields with byte symbols.
+///
+/// Construction computes the products of every symbol with the generator polynomial:
+/// a table of 256 rows of `ecc_len` symbols. Encoding a data symbol is then a single row lookup
+/// added to the division register, instead of `ecc_len` field multiplications.
+/// Codewords are the same as of `Encoder` with the same field and `ecc_len`.
+///
+/// Rows are polynomials of the field, so the table takes `256 * capacity` bytes: 64 KiB
+/// with `Gf2_8` whatever `ecc_len` is. Constructors build it on the stack and return it
+/// by value, which small stacks of embedded targets and threads may not fit.
+/// With `Heap<Gf2_8>` rows are `Vec`s of `ecc_len` symbols, 8 KiB on the heap
+/// for RS(255, 223). With `Capacity<Gf2_8, N>` rows take `N` bytes, but `encode`
+/// is then limited to codewords of `N` symbols too; `encode_in_place` and `compute_parity`
+/// aren't.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::{Encoder, TableEncoder};
+///
+/// let encoder = TableEncoder::new(32);
+///
+/// let data = [42; 223];
+/// let encoded = encoder.encode(&data);
+/// assert_eq!(&Encoder::new(32).encode(&data)[..], &encoded[..]);
+/// ```
+#[derive(Debug)]
+pub struct TableEncoder<F: Field<Elem = u8> = Gf2_8> {
+    /// Row `c` is `c` times the generator polynomial without its leading term
+    rows: [Polynom<F>; 256],
+    ecc_len: usize,
+    field: F,
+}
+
+impl TableEncoder {
+    /// Constructs a new `TableEncoder` over the default GF(2^8) field
+    /// with `ecc_len` symbols of ecc.
+    ///
+    /// # Panics
+    /// Same as `with_roots`.
+    pub fn new(ecc_len: usize) -> Self {
+        TableEncoder::with_field(Gf2_8, ecc_len)
+    }
+}
+
+impl<F: Field<Elem = u8>> TableEncoder<F> {
+    /// Constructs a new `TableEncoder` over given `field`
+    /// and calculates multiplication tables of its generator polynomial.
+    ///
+    /// # Panics
+    /// Same as `with_roots`.
+    pub fn with_field(field: F, ecc_len: usize) -> Self {
+        TableEncoder::with_roots(field, ecc_len, 0, 1)
+    }
+
+    /// Constructs a new `TableEncoder` over given `field` with roots of the generator
+    /// polynomial same as of `Encoder::with_roots`.
+    ///
+    /// # Panics
+    /// Panics if `prim` is zero or shares a factor with `order - 1`,
+    /// if `ecc_len` is longer than 255, the longest codeword of a byte field,
+    /// or if `ecc_len` is not less than `Polynom::capacity()` of the field.
+    pub fn with_roots(field: F, ecc_len: usize, fcr: usize, prim: usize) -> Self {
+        assert!(ecc_len <= MAX_BYTE_ECC_LEN, "codewords of byte fields are at most 255 symbols long");
+        assert!(ecc_len < Polynom::<F>::capacity(),
+                "ecc_len must be less than polynomial capacity of the field");
+        let base = ::gf::root_base(&field, prim);
+        let generator = generator_poly(ecc_len, fcr, base, &field);
+
+        let rows = core::array::from_fn(|c| {
+            let mut row = Polynom::with_length(ecc_len);
+            let c = c as u8;
+            if field.contains(c) {
+                for (x, g) in row.iter_mut().zip(generator[1..].iter()) {
+                    *x = field.mul(c, *g);
+                }
+            }
+            row
+        });
+
+        TableEncoder { rows, ecc_len, field }
+    }
+
+    /// Encodes passed slice and returns `Buffer` with result and `ecc` offset.
+    ///
+    /// # Panics
+    /// Panics if `data` is rejected by `try_encode`.
+    pub fn encode(&self, data: &[u8]) -> Buffer<F> {
+        match self.try_encode(data) {
+            Ok(buffer) => buffer,
+            Err(EncoderError::InvalidSymbol) => panic!("data symbol doesn't belong to the field"),
+            Err(EncoderError::TooLong) => panic!("codeword is too long for the field"),
+            Err(EncoderError::TooShort) | Err(EncoderError::ParityLength) => unreachable!(),
+        }
+    }
+
+    /// Encodes passed slice and returns `Buffer` with result and `ecc` offset.
+    /// Fails same as `Encoder::try_encode`.
+    pub fn try_encode(&self, data: &[u8]) -> Result<Buffer<F>, EncoderError> {
+        // The rest is checked by `encode_in_place`
+        if data.len() + self.ecc_len > Polynom::<F>::capacity() {
+            return Err(EncoderError::TooLong);
+        }
+
+        let mut data_out = Polynom::from(data);
+        data_out.set_length(data.len() + self.ecc_len);
+        self.encode_in_place(&mut data_out)?;
+
+        Ok(Buffer::from_polynom(data_out, data.len()))
+    }
+
+    /// Same as `Encoder::encode_in_place`
+    pub fn encode_in_place(&self, codeword: &mut [u8]) -> Result<(), EncoderError> {
+        let data_len = check_codeword(&self.field, codeword, self.ecc_len)?;
+        let (data, ecc) = codeword.split_at_mut(data_len);
+        self.parity(data, ecc);
+        Ok(())
+    }
+
+    /// Same as `Encoder::compute_parity`
+    pub fn compute_parity(&self, data: &[u8], parity_out: &mut [u8]) -> Result<(), EncoderError> {
+        if parity_out.len() != self.ecc_len {
+            return Err(EncoderError::ParityLength);
+        }
+
+        check_data(&self.field, data, self.ecc_len)?;
+        self.parity(data, parity_out);
+        Ok(())
+    }
+
+    /// Writes `ecc` of already checked `data` to `parity_out` of `ecc_len` symbols
+    fn parity(&self, data: &[u8], parity_out: &mut [u8]) {
+        // `parity_out` is the division register, which holds the negated remainder
+        let ecc_len = self.ecc_len;
+        let register = parity_out;
+        for x in register.iter_mut() {
+            *x = 0;
+        }
+
+        if ecc_len != 0 {
+            for x in data.iter() {
+                let row = &self.rows[self.field.sub(*x, register[0]) as usize];
+                for i in 0..ecc_len - 1 {
+                    register[i] = self.field.add(register[i + 1], row[i]);
+                }
+                register[ecc_len - 1] = row[ecc_len - 1];
+            }
+        }
+    }
+}
+
+
+/// Checks that symbols of `data` belong to `field`, and that a codeword of `data`
+/// and `rest_len` other symbols is at most `order - 1` symbols long
+fn check_data<F: Field>(field: &F, data: &[F::Elem], rest_len: usize) -> Result<(), EncoderError> {
+    if data.len() + rest_len > field.order() - 1 {
+        return Err(EncoderError::TooLong);
+    }
+
+    if data.iter().any(|&x| !field.contains(x)) {
+        return Err(EncoderError::InvalidSymbol);
+    }
+
+    Ok(())
+}
+
+/// Same as `check_data` for `codeword` of data followed by `ecc_len` symbols of ecc,
+/// returns the data length
+fn check_codeword<F: Field>(field: &F, codeword: &[F::Elem], ecc_len: usize) -> Result<usize, EncoderError> {
+    if codeword.len() < ecc_len {
+        return Err(EncoderError::TooShort);
+    }
+
+    let data_len = codeword.len() - ecc_len;
+    check_data(field, &codeword[..data_len], ecc_len)?;
+    Ok(data_len)
+}
+
+/// Product of `(x - base^(fcr + i))` for `i` below `ecclen`
+fn generator_poly<F: Field>(ecclen: usize, fcr: usize, base: F::Elem, field: &F) -> Polynom<F> {
+    let mut gen = polynom![F::Elem::ONE];
+    let mut mm = [F::Elem::ONE, F::Elem::ZERO];
     for i in 0..ecclen {
-        mm[1] = gf::pow(2, i as i32);
-        gen = gen.mul(&mm);
+        mm[1] = field.neg(field.pow(base, (fcr + i) as i32));
+        gen = gen.mul(&mm, field);
     }
     gen
 }
@@ -77,9 +597,11 @@ fn generator_poly(ecclen: usize) -> Polynom {
 
 #[cfg(test)]
 mod tests {
+    use ::gf::Gf2_8;
+
     #[test]
     fn generator_poly() {
-        let answers =
+        let answers: [::gf::poly::Polynom<Gf2_8>; 6] =
             [polynom![1, 3, 2],
              polynom![1, 15, 54, 120, 64],
              polynom![1, 255, 11, 81, 54, 239, 173, 200, 24],
@@ -93,8 +615,8 @@ mod tests {
                       242, 245]];
 
         let mut ecclen = 2;
-        for i in 0..6 {
-            assert_eq!(*answers[i], *super::generator_poly(ecclen));
+        for answer in answers.iter() {
+            assert_eq!(**answer, *super::generator_poly(ecclen, 0, 2, &Gf2_8));
             ecclen *= 2;
         }
     }
@@ -112,4 +634,190 @@ mod tests {
         assert_eq!(ecc, encoded.ecc());
     }
 
+    #[test]
+    fn encode_in_place() {
+        let data = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
+                    22, 23, 24, 25, 26, 27, 28, 29];
+        let ecc = [99, 26, 219, 193, 9, 94, 186, 143];
+
+        let encoder = super::Encoder::new(ecc.len());
+        let mut codeword = [0xff; 38];
+        codeword[..30].copy_from_slice(&data);
+        encoder.encode_in_place(&mut codeword).unwrap();
+        assert_eq!(data, codeword[..30]);
+        assert_eq!(ecc, codeword[30..]);
+
+        // Parity only
+        let mut codeword = [0xff; 8];
+        encoder.encode_in_place(&mut codeword).unwrap();
+        assert_eq!([0; 8], codeword);
+
+        assert_eq!(Some(super::EncoderError::TooShort), encoder.encode_in_place(&mut [0; 7]).err());
+        assert_eq!(Some(super::EncoderError::TooLong), encoder.encode_in_place(&mut [0; 256]).err());
+
+        let mut codeword = [1, 2, 3];
+        super::Encoder::new(0).encode_in_place(&mut codeword).unwrap();
+        assert_eq!([1, 2, 3], codeword);
+    }
+
+    #[test]
+    fn streaming() {
+        let data = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
+                    22, 23, 24, 25, 26, 27, 28, 29];
+        let ecc = [99, 26, 219, 193, 9, 94, 186, 143];
+
+        let encoder = super::Encoder::new(ecc.len());
+        for chunk_len in 1..data.len() + 1 {
+            let mut state = encoder.start();
+            for chunk in data.chunks(chunk_len) {
+                state.update(chunk).unwrap();
+                state.update(&[]).unwrap();
+            }
+            assert_eq!(30, state.data_len());
+            assert_eq!(ecc, *state.finalize());
+        }
+
+        let mut state = encoder.start();
+        state.update(&[0; 200]).unwrap();
+        assert_eq!(Some(super::EncoderError::TooLong), state.update(&[0; 48]).err());
+        state.update(&[0; 47]).unwrap();
+        assert_eq!([0; 8], *state.finalize());
+
+        let encoder = super::Encoder::with_field(::gf::BinaryField::new(0x13, 2), 4);
+        let mut state = encoder.start().clone();
+        assert_eq!(Some(super::EncoderError::InvalidSymbol), state.update(&[1, 16]).err());
+        assert_eq!(0, state.data_len());
+    }
+
+    #[test]
+    fn table_encoder() {
+        use ::gf::{BinaryField, Gf2_8Bitwise};
+
+        let data = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
+                    22, 23, 24, 25, 26, 27, 28, 29];
+        let ecc = [99, 26, 219, 193, 9, 94, 186, 143];
+
+        let encoder = super::TableEncoder::new(8);
+        let encoded = encoder.encode(&data);
+        assert_eq!(data, encoded.data());
+        assert_eq!(ecc, encoded.ecc());
+
+        let mut parity = [0; 8];
+        encoder.compute_parity(&data, &mut parity).unwrap();
+        assert_eq!(ecc, parity);
+        assert_eq!(Some(super::EncoderError::TooLong), encoder.try_encode(&[0; 248]).err());
+
+        let long = [0xa5; 223];
+        let expected = super::Encoder::with_field(Gf2_8Bitwise, 32).encode(&long);
+        assert_eq!(&expected[..], &super::TableEncoder::with_field(Gf2_8Bitwise, 32).encode(&long)[..]);
+        #[cfg(feature = "alloc")]
+        assert_eq!(&expected[..], &super::TableEncoder::with_field(::gf::Heap(Gf2_8), 32).encode(&long)[..]);
+
+        let field = BinaryField::new(0x13, 2);
+        let expected = super::Encoder::with_field(field.clone(), 4).encode(&[1, 2, 15]);
+        let encoder = super::TableEncoder::with_field(field, 4);
+        assert_eq!(&expected[..], &encoder.encode(&[1, 2, 15])[..]);
+        assert_eq!(Some(super::EncoderError::InvalidSymbol), encoder.try_encode(&[1, 2, 16]).err());
+
+        assert_eq!([7, 7], **super::TableEncoder::new(0).encode(&[7, 7]));
+
+        let small = super::TableEncoder::with_field(::gf::Capacity::<_, 16>(Gf2_8), 8);
+        let mut codeword = [0; 38];
+        codeword[..30].copy_from_slice(&data);
+        small.encode_in_place(&mut codeword).unwrap();
+        assert_eq!(ecc, codeword[30..]);
+        assert_eq!(Some(super::EncoderError::TooLong), small.try_encode(&data).err());
+    }
+
+    #[test]
+    fn compute_parity() {
+        let data = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
+                    22, 23, 24, 25, 26, 27, 28, 29];
+        let ecc = [99, 26, 219, 193, 9, 94, 186, 143];
+
+        let encoder = super::Encoder::new(ecc.len());
+        let mut parity = [0xff; 8];
+        encoder.compute_parity(&data, &mut parity).unwrap();
+        assert_eq!(ecc, parity);
+
+        assert_eq!(Some(super::EncoderError::ParityLength), encoder.compute_parity(&data, &mut [0; 7]).err());
+        assert_eq!(Some(super::EncoderError::ParityLength), encoder.compute_parity(&data, &mut [0; 9]).err());
+        assert_eq!(Some(super::EncoderError::TooLong), encoder.compute_parity(&[0; 248], &mut parity).err());
+
+        // In place encoding builds no polynomial, so it isn't limited by their capacity
+        let small = super::Encoder::with_field(::gf::Capacity::<_, 16>(Gf2_8), 8);
+        let mut codeword = [0; 38];
+        codeword[..30].copy_from_slice(&data);
+        small.encode_in_place(&mut codeword).unwrap();
+        assert_eq!(ecc, codeword[30..]);
+        assert_eq!(Some(super::EncoderError::TooLong), small.try_encode(&data).err());
+    }
+
+    #[test]
+    fn with_roots() {
+        use ::gf::Field;
+
+        let field = Gf2_8;
+        let encoder = super::Encoder::with_roots(field, 6, 112, 11);
+        let base = field.pow(2, 11);
+        for i in 0..6 {
+            assert_eq!(0, encoder.generator.eval(field.pow(base, 112 + i), &field));
+        }
+        assert_eq!(*super::Encoder::new(6).generator, *super::Encoder::with_roots(field, 6, 0, 1).generator);
+
+        let data = [0xa5; 50];
+        let expected = encoder.encode(&data);
+        let table = super::TableEncoder::with_roots(Gf2_8, 6, 112, 11);
+        assert_eq!(&expected[..], &table.encode(&data)[..]);
+    }
+
+    #[test]
+    fn encode_batch() {
+        use ::gf::BinaryField;
+        use std::vec::Vec;
+
+        fn check<const L: usize>(ecc_len: usize) {
+            let encoder = super::Encoder::new(ecc_len);
+            let mut codewords: [Vec<u8>; L] = core::array::from_fn(|l| {
+                let data_len = 255 - ecc_len - l * 3;
+                (0..data_len + ecc_len).map(|i| if i < data_len { (i * 7 + l * 31) as u8 } else { 0xee }).collect()
+            });
+            let mut expected = codewords.clone();
+
+            encoder.encode_batch(&mut codewords).unwrap();
+            for (codeword, expected) in codewords.iter().zip(expected.iter_mut()) {
+                encoder.encode_in_place(expected).unwrap();
+                assert_eq!(expected, codeword);
+            }
+        }
+
+        check::<16>(32);
+        check::<32>(16);
+        check::<64>(1);
+        check::<3>(0);
+
+        let encoder = super::Encoder::new(4);
+        let mut codewords = [[1u8; 6], [2; 6]];
+        assert_eq!(Some(super::EncoderError::TooLong), encoder.encode_batch(&mut [[0u8; 256]]).err());
+        assert_eq!(Some(super::EncoderError::TooShort), encoder.encode_batch(&mut [&mut [0u8; 3][..]]).err());
+        encoder.encode_batch(&mut codewords).unwrap();
+        assert_eq!(&encoder.encode(&[2, 2])[..], &codewords[1][..]);
+        assert!(encoder.encode_batch(&mut [[0u8; 0]; 0]).is_ok());
+
+        // Like `encode_in_place`, not limited by polynomial capacity
+        let small = super::Encoder::with_field(::gf::Capacity::<_, 16>(Gf2_8), 4);
+        let mut codewords = [[9u8; 40]; 2];
+        small.encode_batch(&mut codewords).unwrap();
+        assert_eq!(&encoder.encode(&[9; 36])[..], &codewords[0][..]);
+
+        let field = BinaryField::new(0x13, 2);
+        let encoder = super::Encoder::with_field(field.clone(), 4);
+        let mut codewords = [[1u8, 2, 15, 0, 0, 0, 0], [3, 4, 5, 0, 0, 0, 0]];
+        encoder.encode_batch(&mut codewords).unwrap();
+        assert_eq!(&encoder.encode(&[1, 2, 15])[..], &codewords[0][..]);
+
+        let mut invalid = [[1u8, 2, 3, 0, 0, 0, 0], [1, 2, 16, 0, 0, 0, 0]];
+        assert_eq!(Some(super::EncoderError::InvalidSymbol), encoder.encode_batch(&mut invalid).err());
+        assert_eq!([1, 2, 3, 0, 0, 0, 0], invalid[0]);
+    }
 }
@@ -0,0 +1,453 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use ::gf::gf2_16::{EXP, LOG};
+use ::gf::{Field, Gf2_16, Heap};
+use ::buffer::Buffer;
+use ::encoder::EncoderError;
+use ::decoder::DecoderError;
+
+const BITS: usize = 16;
+const ORDER: usize = 1 << BITS;
+/// Order of the multiplicative group, logarithms are taken modulo it
+const MODULUS: u32 = ORDER as u32 - 1;
+
+/// Novel polynomial basis of Lin, Chung and Han over GF(2^16).
+///
+/// With `v_i = 2^i` as the basis of the field over GF(2), `W_i(x)` is the product of `x - a`
+/// over the span `V_i` of `v_0..v_i`, a linearized polynomial, and `Ŵ_i = W_i / W_i(v_i)`.
+/// Polynomials are represented in the basis `X_j`, the product of `Ŵ_i` over set bits `i` of `j`,
+/// and evaluation points are symbols themselves: point `j` is `j` as a field element.
+#[derive(Debug)]
+struct Basis {
+    /// `Ŵ_i(v_b)`, zero for `b < i`
+    vanishing: [[u16; BITS]; BITS],
+    /// `Ŵ_i'`, a constant since `Ŵ_i` is linearized
+    derivative: [u16; BITS],
+}
+
+impl Basis {
+    fn new() -> Basis {
+        let field = Gf2_16;
+
+        // W_i(v_b) for the current i: W_0(x) = x, W_{i+1}(x) = W_i(x) * (W_i(x) + W_i(v_i))
+        let mut w = [0u16; BITS];
+        for (b, x) in w.iter_mut().enumerate() {
+            *x = 1 << b;
+        }
+
+        // Linear coefficient of W_{i+1} is the one of W_i times W_i(v_i)
+        let mut linear = 1;
+        let mut basis = Basis { vanishing: [[0; BITS]; BITS], derivative: [0; BITS] };
+        let rows = basis.vanishing.iter_mut().zip(basis.derivative.iter_mut());
+        for (i, (vanishing, derivative)) in rows.enumerate() {
+            let w_v = w[i];
+            for (y, x) in vanishing.iter_mut().zip(w.iter()) {
+                *y = field.div(*x, w_v);
+            }
+            *derivative = field.div(linear, w_v);
+
+            linear = field.mul(linear, w_v);
+            for x in w.iter_mut() {
+                *x = field.mul(*x, *x ^ w_v);
+            }
+        }
+
+        basis
+    }
+
+    /// `Ŵ_i(x)`
+    #[inline]
+    fn eval(&self, i: usize, x: usize) -> u16 {
+        let mut y = 0;
+        for b in i..BITS {
+            if x >> b & 1 != 0 {
+                y ^= self.vanishing[i][b];
+            }
+        }
+        y
+    }
+
+    /// Evaluates polynomial of `data.len()` coefficients in the novel basis
+    /// at points `beta..beta + data.len()`, in place. `beta` is a multiple of `data.len()`.
+    fn fft(&self, data: &mut [u16], beta: usize) {
+        let field = Gf2_16;
+        let mut level = data.len().trailing_zeros() as usize;
+        while level > 0 {
+            level -= 1;
+            let half = 1 << level;
+            for offset in (0..data.len()).step_by(2 * half) {
+                let skew = self.eval(level, beta ^ offset);
+                let (low, high) = data[offset..offset + 2 * half].split_at_mut(half);
+                for (a, b) in low.iter_mut().zip(high.iter_mut()) {
+                    *a ^= field.mul(skew, *b);
+                    *b ^= *a;
+                }
+            }
+        }
+    }
+
+    /// Inverse of `fft`: coefficients in the novel basis from values
+    /// at points `beta..beta + data.len()`, in place.
+    fn ifft(&self, data: &mut [u16], beta: usize) {
+        let field = Gf2_16;
+        let levels = data.len().trailing_zeros() as usize;
+        for level in 0..levels {
+            let half = 1 << level;
+            for offset in (0..data.len()).step_by(2 * half) {
+                let skew = self.eval(level, beta ^ offset);
+                let (low, high) = data[offset..offset + 2 * half].split_at_mut(half);
+                for (a, b) in low.iter_mut().zip(high.iter_mut()) {
+                    *b ^= *a;
+                    *a ^= field.mul(skew, *b);
+                }
+            }
+        }
+    }
+
+    /// Formal derivative in the novel basis: `X_j'` is the sum of `Ŵ_i' X_(j - 2^i)`
+    /// over set bits `i` of `j`
+    fn derivative(&self, data: &[u16]) -> Vec<u16> {
+        let field = Gf2_16;
+        let mut out = vec![0; data.len()];
+        for (j, x) in data.iter().enumerate() {
+            if *x == 0 {
+                continue;
+            }
+            for i in 0..BITS {
+                if j >> i & 1 != 0 {
+                    out[j ^ 1 << i] ^= field.mul(self.derivative[i], *x);
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Fast Walsh-Hadamard transform modulo `MODULUS`, in place
+fn fwht(data: &mut [u32]) {
+    let mut half = 1;
+    while half < data.len() {
+        for offset in (0..data.len()).step_by(2 * half) {
+            for i in offset..offset + half {
+                let (a, b) = (data[i], data[i + half]);
+                data[i] = (a + b) % MODULUS;
+                data[i + half] = (a + MODULUS - b) % MODULUS;
+            }
+        }
+        half *= 2;
+    }
+}
+
+/// Block size of the code: the number of parity symbols rounded up to a power of two
+#[inline]
+fn block_len(ecc_len: usize) -> usize {
+    ecc_len.next_power_of_two()
+}
+
+/// Reed-Solomon encoder over GF(2^16) using the Lin-Chung-Han additive FFT.
+///
+/// Encoding takes O(n log(ecc_len)) instead of O(n * ecc_len) of `Encoder`, which makes
+/// codes with thousands of parity symbols practical. Codewords differ from those of `Encoder`:
+/// the code evaluates polynomials at points of a subspace of the field rather than
+/// at powers of the generator, so they are decoded with `FftDecoder` only.
+///
+/// Codewords are `data` followed by `ecc_len` parity symbols. `data.len()` plus `ecc_len`
+/// rounded up to a power of two must not exceed 65536.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::{FftEncoder, FftDecoder};
+///
+/// let encoder = FftEncoder::new(1000);
+/// let decoder = FftDecoder::new(1000);
+///
+/// let data: Vec<u16> = (0..30000).collect();
+/// let mut encoded = encoder.encode(&data);
+///
+/// // Any 1000 symbols can be lost
+/// let erasures: Vec<u16> = (10000..11000).collect();
+/// for &pos in &erasures {
+///     encoded[pos as usize] = 0;
+/// }
+///
+/// let recovered = decoder.correct(&encoded, &erasures).unwrap();
+/// assert_eq!(&data[..], recovered.data());
+/// ```
+#[derive(Debug)]
+pub struct FftEncoder {
+    ecc_len: usize,
+    basis: Basis,
+}
+
+impl FftEncoder {
+    /// Constructs a new `FftEncoder` producing `ecc_len` parity symbols
+    pub fn new(ecc_len: usize) -> Self {
+        FftEncoder {
+            ecc_len,
+            basis: Basis::new(),
+        }
+    }
+
+    /// Encodes `data` and returns `Buffer` with result and `ecc` offset.
+    ///
+    /// # Panics
+    /// Panics if `data` is rejected by `try_encode`.
+    pub fn encode(&self, data: &[u16]) -> Buffer<Heap<Gf2_16>> {
+        match self.try_encode(data) {
+            Ok(buffer) => buffer,
+            Err(_) => panic!("codeword is too long for the field"),
+        }
+    }
+
+    /// Encodes `data` and returns `Buffer` with result and `ecc` offset.
+    ///
+    /// Returns `EncoderError::TooLong` if `data.len()` plus `ecc_len` rounded up
+    /// to a power of two exceeds 65536.
+    pub fn try_encode(&self, data: &[u16]) -> Result<Buffer<Heap<Gf2_16>>, EncoderError> {
+        // Parity symbols are values at points 0..m, data at points m..m + data.len()
+        let m = block_len(self.ecc_len);
+        if data.len() + m > ORDER {
+            return Err(EncoderError::TooLong);
+        }
+
+        // Sum of polynomials interpolating each block of data makes the whole codeword
+        // a polynomial of degree less than the code length minus m, see `FftDecoder`
+        let mut parity = vec![0; m];
+        let mut block = vec![0; m];
+        for (i, chunk) in data.chunks(m).enumerate() {
+            block[..chunk.len()].copy_from_slice(chunk);
+            for x in block[chunk.len()..].iter_mut() {
+                *x = 0;
+            }
+            self.basis.ifft(&mut block, (i + 1) * m);
+            for (p, x) in parity.iter_mut().zip(block.iter()) {
+                *p ^= *x;
+            }
+        }
+        self.basis.fft(&mut parity, 0);
+
+        let mut buffer = Buffer::from_slice(data, data.len());
+        buffer.append(&parity[..self.ecc_len]);
+        Ok(buffer)
+    }
+}
+
+/// Erasure-only Reed-Solomon decoder for codes of `FftEncoder`.
+///
+/// Codewords of `FftEncoder` with `n` symbols are values of polynomials of degree
+/// less than `N - m` at points `0..N`, where `m` is `ecc_len` rounded up to a power of two
+/// and `N` is the code length rounded up to a power of two. Unused points are zero or
+/// always erased. Erased values `F(e)` are recovered from the formal derivative of `ℓF`,
+/// where `ℓ` is the erasure locator: `(ℓF)'(e) = ℓ'(e) F(e)`. Both the locator and
+/// the derivative are computed with transforms, so decoding takes O(N log N).
+#[derive(Debug)]
+pub struct FftDecoder {
+    ecc_len: usize,
+    basis: Basis,
+}
+
+impl FftDecoder {
+    /// Constructs a new `FftDecoder` for codewords with `ecc_len` parity symbols
+    pub fn new(ecc_len: usize) -> Self {
+        FftDecoder {
+            ecc_len,
+            basis: Basis::new(),
+        }
+    }
+
+    /// Recovers erased symbols of `msg` at positions `erase_pos` and returns `Buffer`
+    /// with corrected message and ecc offset. Values of erased symbols are ignored.
+    ///
+    /// Errors at unknown positions are not corrected. Returns `DecoderError::TooManyErrors`
+    /// if there are more than `ecc_len` erasures, `TooShort` if `msg` is shorter than `ecc_len`,
+    /// `TooLong` if it's too long for the code and `InvalidErasure` if an erasure position
+    /// is out of `msg`.
+    pub fn correct(&self, msg: &[u16], erase_pos: &[u16])
+                   -> Result<Buffer<Heap<Gf2_16>>, DecoderError> {
+        if msg.len() < self.ecc_len {
+            return Err(DecoderError::TooShort);
+        }
+        let data_len = msg.len() - self.ecc_len;
+        let m = block_len(self.ecc_len);
+        if data_len + m > ORDER {
+            return Err(DecoderError::TooLong);
+        }
+        if erase_pos.iter().any(|&pos| pos as usize >= msg.len()) {
+            return Err(DecoderError::InvalidErasure);
+        }
+        let n = (data_len + m).next_power_of_two();
+
+        // Codeword position to evaluation point
+        let point = |pos: usize| if pos < data_len { m + pos } else { pos - data_len };
+
+        // Unused parity points are erased as well
+        let mut erased = vec![false; n];
+        for x in erased[self.ecc_len..m].iter_mut() {
+            *x = true;
+        }
+        for &pos in erase_pos {
+            erased[point(pos as usize)] = true;
+        }
+
+        if erased.iter().filter(|&&x| x).count() > m {
+            return Err(DecoderError::TooManyErrors);
+        }
+
+        let mut buffer = Buffer::from_slice(msg, data_len);
+        if erase_pos.is_empty() {
+            return Ok(buffer);
+        }
+
+        // Logarithms of ℓ(x) for x not erased and of ℓ'(x) for erased x,
+        // a dyadic convolution of erasures with the logarithm table with log(0) taken as 0
+        let mut locator: Vec<u32> = erased.iter().map(|&x| x as u32).collect();
+        let mut logs: Vec<u32> = LOG[..n].iter().map(|&x| x as u32).collect();
+        logs[0] = 0;
+        fwht(&mut locator);
+        fwht(&mut logs);
+        // Both factors are reduced modulo 2^16 - 1, so the product fits into u32
+        for (l, x) in locator.iter_mut().zip(logs.iter()) {
+            *l = *l * *x % MODULUS;
+        }
+        fwht(&mut locator);
+        // The transform applied twice scales by n, and n^-1 = 2^16 / n modulo 2^16 - 1.
+        // `scale <= 2^16` and `l < 2^16 - 1`, so `scale * l < 2^32`
+        let scale = (ORDER / n) as u32;
+        for l in locator.iter_mut() {
+            *l = *l * scale % MODULUS;
+        }
+
+        // Values of ℓF at every point, zero at erasures
+        let field = Gf2_16;
+        let mut values = vec![0; n];
+        for (pos, x) in msg.iter().enumerate() {
+            let p = point(pos);
+            if !erased[p] {
+                values[p] = field.mul(*x, EXP[locator[p] as usize]);
+            }
+        }
+
+        self.basis.ifft(&mut values, 0);
+        let mut derivative = self.basis.derivative(&values);
+        self.basis.fft(&mut derivative, 0);
+
+        for &pos in erase_pos {
+            let p = point(pos as usize);
+            let inverse = (MODULUS - locator[p]) % MODULUS;
+            buffer[pos as usize] = field.mul(derivative[p], EXP[inverse as usize]);
+        }
+
+        Ok(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// xorshift32
+    fn samples(len: usize, mut seed: u32) -> Vec<u16> {
+        (0..len).map(|_| {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            seed as u16
+        }).collect()
+    }
+
+    #[test]
+    fn transforms() {
+        let basis = Basis::new();
+        let data = samples(64, 1);
+
+        let mut values = data.clone();
+        basis.fft(&mut values, 128);
+        basis.ifft(&mut values, 128);
+        assert_eq!(data, values);
+
+        // Product of Ŵ_i over set bits of j at point x, evaluated directly
+        let novel = |j: usize, x: usize| (0..BITS).filter(|i| j >> i & 1 != 0)
+                                                   .fold(1, |acc, i| Gf2_16.mul(acc, basis.eval(i, x)));
+
+        let mut values = data.clone();
+        basis.fft(&mut values, 192);
+        for (l, y) in values.iter().enumerate() {
+            let expected = data.iter().enumerate()
+                               .fold(0, |acc, (j, d)| acc ^ Gf2_16.mul(*d, novel(j, 192 + l)));
+            assert_eq!(expected, *y);
+        }
+
+        // Ŵ_i vanishes on V_i and is one at v_i
+        for i in 0..BITS {
+            assert_eq!(0, basis.eval(i, (1 << i) - 1));
+            assert_eq!(1, basis.eval(i, 1 << i));
+        }
+    }
+
+    #[test]
+    fn derivative() {
+        // x^2 + x is Ŵ_1 times W_1(v_1), its derivative is one
+        let basis = Basis::new();
+        let mut values: Vec<u16> = (0..4).map(|x: u16| Gf2_16.mul(x, x) ^ x).collect();
+        basis.ifft(&mut values, 0);
+        let mut derivative = basis.derivative(&values);
+        basis.fft(&mut derivative, 0);
+        assert_eq!([1, 1, 1, 1], *derivative);
+    }
+
+    #[test]
+    fn erasures() {
+        for &(data_len, ecc_len) in &[(1, 1), (10, 3), (100, 16), (1000, 37), (5000, 600)] {
+            let encoder = FftEncoder::new(ecc_len);
+            let decoder = FftDecoder::new(ecc_len);
+
+            let data = samples(data_len, data_len as u32);
+            let encoded = encoder.encode(&data);
+            assert_eq!(data_len + ecc_len, encoded.len());
+            assert_eq!(&data[..], encoded.data());
+
+            // Data, parity and mixed erasures, up to ecc_len
+            let len = encoded.len();
+            let patterns: [Vec<u16>; 3] = [
+                (0..ecc_len.min(data_len)).map(|x| x as u16).collect(),
+                (data_len..len).map(|x| x as u16).collect(),
+                (0..ecc_len).map(|x| (x * len / ecc_len) as u16).collect(),
+            ];
+            for erasures in patterns.iter() {
+                let mut corrupted = encoded.clone();
+                for &pos in erasures.iter() {
+                    corrupted[pos as usize] ^= 0x5a5a;
+                }
+                let recovered = decoder.correct(&corrupted, erasures).unwrap();
+                assert_eq!(&encoded[..], &recovered[..]);
+            }
+        }
+    }
+
+    #[test]
+    fn limits() {
+        let decoder = FftDecoder::new(4);
+        let encoded = FftEncoder::new(4).encode(&[1, 2, 3]);
+        assert!(decoder.correct(&encoded, &[0, 1, 2, 3, 4]).is_err());
+        assert_eq!(&[1, 2, 3], decoder.correct(&encoded, &[]).unwrap().data());
+        // Duplicate positions count once
+        assert!(decoder.correct(&encoded, &[0, 0, 1, 2, 3]).is_ok());
+
+        assert!(matches!(decoder.correct(&encoded[..3], &[]), Err(DecoderError::TooShort)));
+        assert!(matches!(decoder.correct(&vec![0; ORDER + 1], &[]), Err(DecoderError::TooLong)));
+        assert!(matches!(decoder.correct(&encoded, &[1, 7]), Err(DecoderError::InvalidErasure)));
+
+        assert!(FftEncoder::new(4).try_encode(&vec![0; ORDER - 4]).is_ok());
+        assert_eq!(Some(EncoderError::TooLong), FftEncoder::new(5).try_encode(&vec![0; ORDER - 4]).err());
+
+        // Longest code of the field
+        let data = samples(ORDER - 1024, 7);
+        let encoded = FftEncoder::new(1000).encode(&data);
+        let erasures: Vec<u16> = (0..1000).map(|x| x * 64).collect();
+        let mut corrupted = encoded.clone();
+        for &pos in erasures.iter() {
+            corrupted[pos as usize] = 0;
+        }
+        assert_eq!(&encoded[..], &FftDecoder::new(1000).correct(&corrupted, &erasures).unwrap()[..]);
+    }
+}
@@ -0,0 +1,242 @@
+use ::gf::poly::Polynom;
+use ::gf::{self, Field, Gf2_8};
+use ::decoder::{Decoder, DecoderError};
+
+/// Reed-Solomon BCH code over GF(2^8) with codewords of `N` symbols and `K` data symbols,
+/// fixed at compile time.
+///
+/// The generator polynomial and its roots are computed at compile time, so constructing
+/// the code computes nothing, and codewords are arrays of `N` symbols that need no length checks.
+/// Encoding and syndromes use the same `mul_add_slice` kernels as `Encoder` and `Decoder`.
+/// Codewords are the same as of `Encoder::new(N - K)`, and are corrected same as
+/// by `Decoder::new(N - K)`.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::ReedSolomon;
+///
+/// let code = ReedSolomon::<12, 8>::new();
+///
+/// let mut codeword = code.encode(b"Hello!!!");
+/// assert!(!code.is_corrupted(&codeword));
+///
+/// codeword[1] = 0;
+/// assert_eq!(b"Hello!!!", &code.correct(&codeword, None).unwrap()[..8]);
+/// ```
+///
+/// Parameters that don't make a code fail to compile. Stable Rust can't bound const parameters
+/// of the type, so they are checked when `new` is instantiated for them: `cargo build`
+/// reports wrong parameters, but `cargo check` accepts them.
+/// ```rust,compile_fail
+/// use reed_solomon::ReedSolomon;
+///
+/// // K >= N
+/// let code = ReedSolomon::<28, 32>::new();
+/// ```
+/// ```rust,compile_fail
+/// use reed_solomon::ReedSolomon;
+///
+/// // N > 255
+/// let code = ReedSolomon::<300, 200>::new();
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct ReedSolomon<const N: usize, const K: usize> {
+    _valid: (),
+}
+
+impl<const N: usize, const K: usize> ReedSolomon<N, K> {
+    /// Number of ecc symbols, `N - K`
+    pub const ECC_LEN: usize = {
+        assert!(0 < K && K < N, "K must be positive and less than N");
+        assert!(N <= 255, "codewords of GF(2^8) are at most 255 symbols long");
+        N - K
+    };
+
+    /// Generator polynomial, highest degree first, padded with zeros after `ECC_LEN + 1` symbols
+    const GENERATOR: [u8; ::POLYNOMIAL_MAX_LENGTH] = generator_poly(Self::ECC_LEN);
+
+    /// Roots of the generator polynomial, `alpha^i` for `i` below `ECC_LEN`, padded with zeros
+    const ROOTS: [u8; ::POLYNOMIAL_MAX_LENGTH] = roots(Self::ECC_LEN, 1);
+
+    /// Roots raised to `BLOCK`, which multiply whole blocks of a codeword in Horner's method
+    const BLOCK_ROOTS: [u8; ::POLYNOMIAL_MAX_LENGTH] = roots(Self::ECC_LEN, BLOCK);
+
+    /// Constructs the code, rejecting wrong `N` and `K` at compile time
+    pub const fn new() -> Self {
+        // Evaluating the constant checks the parameters
+        let _ecc_len = Self::ECC_LEN;
+        ReedSolomon { _valid: () }
+    }
+
+    /// Generator polynomial of the code, same as of `Encoder::new(N - K)`
+    pub fn generator(&self) -> Polynom<Gf2_8> {
+        Polynom::from(&Self::GENERATOR[..Self::ECC_LEN + 1])
+    }
+
+    /// Encodes `data` and returns the codeword: `data` followed by the ecc
+    pub fn encode(&self, data: &[u8; K]) -> [u8; N] {
+        let mut codeword = [0; N];
+        codeword[..K].copy_from_slice(data);
+        self.encode_in_place(&mut codeword);
+        codeword
+    }
+
+    /// Encodes data in the first `K` symbols of `codeword` and writes the ecc
+    /// to its last `N - K` symbols
+    pub fn encode_in_place(&self, codeword: &mut [u8; N]) {
+        let generator = Self::GENERATOR;
+        let gen = &generator[1..Self::ECC_LEN + 1];
+
+        // Division register, holds the remainder, which equals the ecc in GF(2^8)
+        let (data, ecc) = codeword.split_at_mut(K);
+        for x in ecc.iter_mut() {
+            *x = 0;
+        }
+        for x in data.iter() {
+            let coef = gf::add(*x, ecc[0]);
+            ecc.copy_within(1.., 0);
+            ecc[Self::ECC_LEN - 1] = 0;
+
+            if coef != 0 {
+                Gf2_8.mul_add_slice(coef, gen, ecc);
+            }
+        }
+    }
+
+    /// Same as `Decoder::is_corrupted`
+    pub fn is_corrupted(&self, codeword: &[u8; N]) -> bool {
+        (0..Self::ECC_LEN).any(|i| Self::syndrome(codeword, i) != 0)
+    }
+
+    /// Corrects `codeword` like `Decoder::correct` and returns the corrected codeword
+    pub fn correct(&self, codeword: &[u8; N], erase_pos: Option<&[u8]>) -> Result<[u8; N], DecoderError> {
+        if erase_pos.unwrap_or(&[]).is_empty() && !self.is_corrupted(codeword) {
+            return Ok(*codeword);
+        }
+
+        let corrected = self.decoder().correct(codeword, erase_pos)?;
+
+        let mut codeword = [0; N];
+        codeword.copy_from_slice(&corrected);
+        Ok(codeword)
+    }
+
+    /// Value of `codeword` at root `i` of the generator polynomial, same as `Decoder` computes:
+    /// Horner's method over blocks, each multiplied by the same power of the root at once
+    fn syndrome(codeword: &[u8; N], i: usize) -> u8 {
+        let root = Self::ROOTS[i];
+        let block_root = Self::BLOCK_ROOTS[i];
+
+        let mut acc = [0; BLOCK];
+        let mut blocks = codeword.chunks_exact(BLOCK);
+        for block in blocks.by_ref() {
+            let mut next = [0; BLOCK];
+            next.copy_from_slice(block);
+            Gf2_8.mul_add_slice(block_root, &acc, &mut next);
+            acc = next;
+        }
+
+        let mut y = 0;
+        for x in acc.iter().chain(blocks.remainder()) {
+            y = gf::add(gf::mul(y, root), *x);
+        }
+        y
+    }
+
+    #[inline]
+    fn decoder(&self) -> Decoder {
+        Decoder::new(Self::ECC_LEN)
+    }
+}
+
+impl<const N: usize, const K: usize> Default for ReedSolomon<N, K> {
+    fn default() -> Self {
+        ReedSolomon::new()
+    }
+}
+
+/// Length of blocks in syndrome computation, same as of `Decoder`
+const BLOCK: usize = 32;
+
+/// `alpha^(i * power)` for `i` below `ecc_len`, padded with zeros
+const fn roots(ecc_len: usize, power: usize) -> [u8; ::POLYNOMIAL_MAX_LENGTH] {
+    let mut step = 1;
+    let mut k = 0;
+    while k < power {
+        step = gf::mul_const(step, 2);
+        k += 1;
+    }
+
+    let mut roots = [0; ::POLYNOMIAL_MAX_LENGTH];
+    let mut root = 1;
+    let mut i = 0;
+    while i < ecc_len {
+        roots[i] = root;
+        root = gf::mul_const(root, step);
+        i += 1;
+    }
+    roots
+}
+
+/// Same as `Encoder`'s generator polynomial, in a constant expression
+const fn generator_poly(ecc_len: usize) -> [u8; ::POLYNOMIAL_MAX_LENGTH] {
+    let roots = roots(ecc_len, 1);
+    let mut gen = [0; ::POLYNOMIAL_MAX_LENGTH];
+    gen[0] = 1;
+
+    // Multiply by (x - alpha^i), highest degree first
+    let mut i = 0;
+    while i < ecc_len {
+        let mut j = i + 1;
+        while j > 0 {
+            gen[j] ^= gf::mul_const(roots[i], gen[j - 1]);
+            j -= 1;
+        }
+        i += 1;
+    }
+    gen
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReedSolomon;
+    use ::{Encoder, Decoder};
+
+    fn check<const N: usize, const K: usize>() {
+        let code = ReedSolomon::<N, K>::new();
+        let encoder = Encoder::new(N - K);
+        // The generator is the codeword of a single one
+        assert_eq!(encoder.encode(&[1])[..], code.generator()[..]);
+
+        let data: [u8; K] = core::array::from_fn(|i| (i * 13 + 7) as u8);
+        let codeword = code.encode(&data);
+        assert_eq!(&encoder.encode(&data)[..], &codeword[..]);
+        assert!(!code.is_corrupted(&codeword));
+
+        let mut corrupted = codeword;
+        corrupted[0] ^= 0x55;
+        assert!(code.is_corrupted(&corrupted));
+        assert_eq!(codeword, code.correct(&corrupted, None).unwrap());
+        assert_eq!(codeword, code.correct(&codeword, None).unwrap());
+        assert_eq!(codeword, code.correct(&corrupted, Some(&[0])).unwrap());
+
+        // Every symbol is covered by the syndromes
+        let decoder = Decoder::new(N - K);
+        for i in 0..N {
+            let mut corrupted = codeword;
+            corrupted[i] ^= 1;
+            assert!(code.is_corrupted(&corrupted));
+            assert!(decoder.is_corrupted(&corrupted));
+        }
+        assert_eq!(Decoder::new(N - K).correct(&corrupted, None).unwrap()[..], codeword[..]);
+    }
+
+    #[test]
+    fn codes() {
+        check::<255, 223>();
+        check::<32, 28>();
+        check::<10, 8>();
+        check::<255, 253>();
+        assert_eq!(32, ReedSolomon::<255, 223>::ECC_LEN);
+    }
+}
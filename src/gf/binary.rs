@@ -0,0 +1,366 @@
+use core::fmt;
+use ::gf::{Field, FieldError};
+
+/// GF(2^m), 3 <= m <= 8, with caller-chosen primitive polynomial and generator.
+///
+/// Degree of the field is the degree of its polynomial, commonly used ones are:
+///
+/// | field     | polynomial | used by                    |
+/// |-----------|------------|----------------------------|
+/// | GF(2^3)   | `0xb`      |                            |
+/// | GF(2^4)   | `0x13`     | QR codes format info       |
+/// | GF(2^5)   | `0x25`     |                            |
+/// | GF(2^6)   | `0x43`     | Aztec, P25                 |
+/// | GF(2^7)   | `0x89`     | ITU-T J.83                 |
+/// | GF(2^8)   | `0x11d`    | QR codes, CD, DVB          |
+///
+/// Codewords over GF(2^m) are at most 2^m - 1 symbols long, and symbols must be less than 2^m.
+///
+/// Exponent and logarithm tables are built by the constructor, so the field is 768 bytes large;
+/// pass it by reference (`&BinaryField` is a `Field` too) to share one set of tables
+/// between encoders and decoders.
+///
+/// Constructors are `const fn`, so a field can be a `static` with its tables generated
+/// at compile time; an invalid polynomial or generator is then a compile error:
+///
+/// ```rust
+/// use reed_solomon::{BinaryField, Encoder};
+///
+/// static GF256_12D: BinaryField = BinaryField::new(0x12d, 2);
+///
+/// let encoder = Encoder::with_field(&GF256_12D, 4);
+/// assert_eq!(8, encoder.encode(&[1, 2, 3, 4]).len());
+/// ```
+///
+/// ```compile_fail
+/// use reed_solomon::BinaryField;
+///
+/// // 0x11b is irreducible, but 2 is not primitive in it
+/// static BAD: BinaryField = BinaryField::new(0x11b, 2);
+/// # fn main() { let _ = &BAD; }
+/// ```
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::{BinaryField, Encoder, Decoder};
+///
+/// // GF(64)
+/// let field = BinaryField::new(0x43, 2);
+///
+/// let encoder = Encoder::with_field(&field, 4);
+/// let decoder = Decoder::with_field(&field, 4);
+///
+/// let mut encoded = encoder.encode(&[1, 2, 3, 63]);
+/// encoded[1] = 0;
+///
+/// assert_eq!(&[1, 2, 3, 63], decoder.correct(&encoded, None).unwrap().data());
+///
+/// // 64 is not a GF(64) symbol
+/// assert!(encoder.try_encode(&[1, 2, 3, 64]).is_err());
+/// ```
+#[derive(Clone)]
+pub struct BinaryField {
+    poly: u16,
+    degree: u32,
+    generator: u8,
+    pub(super) exp: [u8; 512],
+    pub(super) log: [u8; 256],
+}
+
+impl BinaryField {
+    /// Constructs a field from `poly` (with the leading term, e.g. `0x11d`) and a `generator`.
+    ///
+    /// # Panics
+    /// Panics if the polynomial or generator is rejected by `try_new`.
+    pub const fn new(poly: u16, generator: u8) -> Self {
+        match Self::try_new(poly, generator) {
+            Ok(field) => field,
+            Err(FieldError::InvalidDegree) => panic!("field polynomial must be of degree 3 to 8"),
+            Err(FieldError::Reducible) => panic!("field polynomial is reducible"),
+            Err(FieldError::NotPrimitive) => panic!("generator is not a primitive element"),
+            Err(FieldError::NotPrime) => panic!("unreachable"),
+        }
+    }
+
+    /// Constructs a field from `poly` (with the leading term, e.g. `0x11d`) and a `generator`.
+    ///
+    /// Polynomial must be of degree 3 to 8 and irreducible,
+    /// `generator` must generate all 2^m - 1 non-zero elements.
+    /// Not every irreducible polynomial is primitive: e.g. for `0x11b` generator 2 is rejected,
+    /// but 3 is accepted.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::{BinaryField, FieldError};
+    ///
+    /// assert!(BinaryField::try_new(0x11b, 3).is_ok());
+    /// assert_eq!(BinaryField::try_new(0x11b, 2).err(), Some(FieldError::NotPrimitive));
+    /// assert_eq!(BinaryField::try_new(0x11c, 2).err(), Some(FieldError::Reducible));
+    /// ```
+    pub const fn try_new(poly: u16, generator: u8) -> Result<Self, FieldError> {
+        if poly == 0 || degree(poly) < 3 || degree(poly) > 8 {
+            return Err(FieldError::InvalidDegree);
+        }
+
+        if !is_irreducible(poly) {
+            return Err(FieldError::Reducible);
+        }
+
+        let mut field = BinaryField {
+            poly,
+            degree: degree(poly),
+            generator,
+            exp: [0; 512],
+            log: [0; 256],
+        };
+
+        let n = field.group_order();
+        if generator as usize > n {
+            return Err(FieldError::NotPrimitive);
+        }
+
+        let mut x = 1u8;
+        let mut i = 0;
+        while i < n {
+            // Each non-zero element must appear exactly once before the cycle closes
+            if x == 0 || (x == 1 && i != 0) || (i != 0 && field.log[x as usize] != 0) {
+                return Err(FieldError::NotPrimitive);
+            }
+
+            field.exp[i] = x;
+            field.log[x as usize] = i as u8;
+            x = mul_mod(x, generator, poly);
+            i += 1;
+        }
+
+        if x != 1 {
+            return Err(FieldError::NotPrimitive);
+        }
+
+        while i < field.exp.len() {
+            field.exp[i] = field.exp[i - n];
+            i += 1;
+        }
+
+        Ok(field)
+    }
+
+    /// Degree of the field: the field has 2^degree elements
+    pub const fn degree(&self) -> u32 {
+        self.degree
+    }
+
+    /// Size of the multiplicative group, 2^m - 1
+    #[inline]
+    const fn group_order(&self) -> usize {
+        (1 << self.degree) - 1
+    }
+
+    /// Field polynomial
+    pub const fn poly(&self) -> u16 {
+        self.poly
+    }
+}
+
+/// Carry-less multiplication of `x` and `y` modulo `poly`
+const fn mul_mod(x: u8, y: u8, poly: u16) -> u8 {
+    let mut x = x as u16;
+    let mut y = y;
+    let mut r = 0u16;
+    while y != 0 {
+        if y & 1 != 0 {
+            r ^= x;
+        }
+        x <<= 1;
+        if x & (1 << degree(poly)) != 0 {
+            x ^= poly;
+        }
+        y >>= 1;
+    }
+    r as u8
+}
+
+/// Degree of a GF(2) polynomial packed into bits
+const fn degree(p: u16) -> u32 {
+    15 - p.leading_zeros()
+}
+
+/// Remainder of GF(2) polynomial division
+const fn rem(mut a: u16, b: u16) -> u16 {
+    let db = degree(b);
+    while a != 0 && degree(a) >= db {
+        a ^= b << (degree(a) - db);
+    }
+    a
+}
+
+/// Trial division by every polynomial of degree up to half of the `poly` degree
+const fn is_irreducible(poly: u16) -> bool {
+    let mut d = 2;
+    while d < 1 << (degree(poly) / 2 + 1) {
+        if rem(poly, d) == 0 {
+            return false;
+        }
+        d += 1;
+    }
+    true
+}
+
+impl Field for BinaryField {
+    type Elem = u8;
+    type Array = [u8; ::POLYNOMIAL_MAX_LENGTH];
+
+    const ZEROS: Self::Array = [0; ::POLYNOMIAL_MAX_LENGTH];
+
+    #[inline]
+    fn order(&self) -> usize {
+        1 << self.degree
+    }
+
+    #[inline]
+    fn primitive(&self) -> u8 {
+        self.generator
+    }
+
+    #[inline]
+    fn add(&self, x: u8, y: u8) -> u8 {
+        x ^ y
+    }
+
+    #[inline]
+    fn sub(&self, x: u8, y: u8) -> u8 {
+        x ^ y
+    }
+
+    #[inline]
+    fn mul(&self, x: u8, y: u8) -> u8 {
+        if x == 0 || y == 0 {
+            0
+        } else {
+            let log = &self.log;
+            let exp = &self.exp;
+            let exp_index = uncheck!(log[x as usize]) as usize + uncheck!(log[y as usize]) as usize;
+            uncheck!(exp[exp_index])
+        }
+    }
+
+    #[inline]
+    fn div(&self, x: u8, y: u8) -> u8 {
+        debug_assert!(y != 0);
+        if x == 0 {
+            0
+        } else {
+            let log = &self.log;
+            let exp = &self.exp;
+            let exp_index = uncheck!(log[x as usize]) as usize + self.group_order()
+                            - uncheck!(log[y as usize]) as usize;
+            uncheck!(exp[exp_index])
+        }
+    }
+
+    #[inline]
+    fn pow(&self, x: u8, power: i32) -> u8 {
+        if x == 0 {
+            return if power == 0 { 1 } else { 0 };
+        }
+
+        let log = &self.log;
+        let exp = &self.exp;
+        let n = self.group_order() as i32;
+        let mut i = uncheck!(log[x as usize]) as i32 * (power % n) % n;
+        if i < 0 {
+            i += n;
+        }
+
+        uncheck!(exp[i as usize])
+    }
+
+    #[inline]
+    fn inverse(&self, x: u8) -> u8 {
+        debug_assert!(x != 0);
+        let log = &self.log;
+        let exp = &self.exp;
+        let exp_index = self.group_order() - uncheck!(log[x as usize]) as usize;
+        uncheck!(exp[exp_index])
+    }
+}
+
+impl fmt::Debug for BinaryField {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("BinaryField")
+            .field("poly", &self.poly)
+            .field("generator", &self.generator)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::gf::{self, Gf2_8};
+
+    #[test]
+    fn matches_default_tables() {
+        let field = BinaryField::new(0x11d, 2);
+        assert_eq!(&gf::EXP[..], &field.exp[..]);
+        assert_eq!(&gf::LOG[..], &field.log[..]);
+
+        for x in 0..256usize {
+            for y in 1..256usize {
+                let (x, y) = (x as u8, y as u8);
+                assert_eq!(Gf2_8.mul(x, y), field.mul(x, y));
+                assert_eq!(Gf2_8.div(x, y), field.div(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn custom_polynomials() {
+        for &(poly, generator) in &[(0x12d, 2), (0x11b, 3), (0x187, 2), (0x169, 2)] {
+            let field = BinaryField::new(poly, generator);
+            for x in 1..256usize {
+                let x = x as u8;
+                assert_eq!(1, field.mul(x, field.inverse(x)));
+                assert_eq!(x, field.pow(generator, field.log[x as usize] as i32));
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert_eq!(Some(FieldError::InvalidDegree), BinaryField::try_new(0x7, 2).err());
+        assert_eq!(Some(FieldError::InvalidDegree), BinaryField::try_new(0, 2).err());
+        assert_eq!(Some(FieldError::InvalidDegree), BinaryField::try_new(0x211, 2).err());
+        assert_eq!(Some(FieldError::Reducible), BinaryField::try_new(0x100, 2).err());
+        assert_eq!(Some(FieldError::Reducible), BinaryField::try_new(0x11f, 2).err());
+        assert_eq!(Some(FieldError::NotPrimitive), BinaryField::try_new(0x11b, 2).err());
+        assert_eq!(Some(FieldError::NotPrimitive), BinaryField::try_new(0x11d, 1).err());
+        assert_eq!(Some(FieldError::NotPrimitive), BinaryField::try_new(0x11d, 0).err());
+        assert_eq!(Some(FieldError::Reducible), BinaryField::try_new(0x1d, 2).err());
+        assert_eq!(Some(FieldError::NotPrimitive), BinaryField::try_new(0x13, 16).err());
+        // x^4 + x^3 + x^2 + x + 1 is irreducible, but x has order 5
+        assert_eq!(Some(FieldError::NotPrimitive), BinaryField::try_new(0x1f, 2).err());
+    }
+
+    #[test]
+    fn small_fields() {
+        for &(poly, order) in &[(0xb, 8), (0x13, 16), (0x25, 32), (0x43, 64), (0x89, 128)] {
+            let field = BinaryField::new(poly, 2);
+            assert_eq!(order, field.order());
+
+            for x in 1..order {
+                let x = x as u8;
+                let x_inv = field.inverse(x);
+                assert!((x_inv as usize) < order);
+                assert_eq!(1, field.mul(x, x_inv));
+                assert_eq!(x_inv, field.pow(x, -1));
+                for y in 1..order {
+                    let y = y as u8;
+                    assert!((field.mul(x, y) as usize) < order);
+                    assert_eq!(x, field.div(field.mul(x, y), y));
+                }
+            }
+            assert_eq!(1, field.pow(2, order as i32 - 1));
+        }
+    }
+}
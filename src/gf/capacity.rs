@@ -0,0 +1,103 @@
+use ::gf::{Field, ConstantTime, Symbol};
+
+/// Field `F` with polynomials and buffers of at most `N` symbols.
+///
+/// Arithmetic is delegated to `F`, only the storage differs: every polynomial is an `N`-symbol
+/// array instead of the field's default one (256 symbols for GF(2^8)), so stack use of the
+/// encoder and decoder scales with the code length. `N` must be at least the length of decoded
+/// codewords and of those returned by `Encoder::encode`, and greater than `ecc_len`:
+/// encoders and decoders with longer ecc panic on construction.
+/// `Encoder::encode_in_place` builds no polynomial, so only the field limits its codewords.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::{Encoder, Decoder, Capacity, Gf2_8, Polynom};
+///
+/// // RS(32, 28)
+/// type Small = Capacity<Gf2_8, 32>;
+///
+/// let encoder: Encoder<Small> = Encoder::with_field(Capacity(Gf2_8), 4);
+/// let decoder: Decoder<Small> = Decoder::with_field(Capacity(Gf2_8), 4);
+///
+/// let data = [7; 28];
+/// let mut encoded = encoder.encode(&data);
+/// encoded[5] = 0;
+///
+/// assert_eq!(&data[..], decoder.correct(&encoded, None).unwrap().data());
+/// assert!(core::mem::size_of::<Polynom<Small>>() < 64);
+///
+/// // Longer codewords don't fit
+/// assert!(encoder.try_encode(&[0; 29]).is_err());
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Capacity<F, const N: usize>(pub F);
+
+impl<F: Field, const N: usize> Field for Capacity<F, N> {
+    type Elem = F::Elem;
+    type Array = [F::Elem; N];
+
+    const ZEROS: Self::Array = [F::Elem::ZERO; N];
+
+    delegate_field_ops!();
+}
+
+impl<F: ConstantTime, const N: usize> ConstantTime for Capacity<F, N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::gf::{Gf2_8, Gf2_8ConstantTime, PrimeField};
+    use ::gf::poly::Polynom;
+    use ::{Encoder, Decoder};
+
+    #[test]
+    fn exact_fit() {
+        // Codeword as long as the capacity, and as many errors as ecc_len allows
+        let ecc_len = 24;
+        let encoder = Encoder::with_field(Capacity::<_, 32>(Gf2_8), ecc_len);
+        let decoder = Decoder::with_field(Capacity::<_, 32>(Gf2_8), ecc_len);
+
+        let data = [1, 2, 3, 4, 5, 6, 7, 8];
+        let encoded = encoder.encode(&data);
+        assert_eq!(&Encoder::new(ecc_len).encode(&data)[..], &encoded[..]);
+
+        let mut corrupted = encoded;
+        for i in 0..10 {
+            corrupted[i * 3] ^= 0xaa;
+        }
+        let erasures = [1, 31, 29, 28];
+        for &pos in erasures.iter() {
+            corrupted[pos as usize] = 0;
+        }
+
+        let (recovered, fixed) = decoder.correct_err_count(&corrupted, Some(&erasures)).unwrap();
+        assert_eq!(&data[..], recovered.data());
+        assert_eq!(14, fixed);
+
+        let decoder = Decoder::with_field(Capacity::<_, 32>(Gf2_8ConstantTime), ecc_len);
+        let recovered = decoder.correct_constant_time(&corrupted, Some(&erasures)).unwrap().0;
+        assert_eq!(&data[..], recovered.data());
+    }
+
+    #[test]
+    fn footprint() {
+        assert_eq!(16, Polynom::<Capacity<Gf2_8, 16>>::capacity());
+        assert!(::core::mem::size_of::<Polynom<Capacity<Gf2_8, 16>>>() <= 40);
+
+        let field = Capacity::<_, 16>(PrimeField::new(929, 3));
+        let encoded = Encoder::with_field(field, 4).encode(&[900, 1, 2]);
+        assert_eq!(&[900, 1, 2], Decoder::with_field(field, 4).correct(&encoded, None).unwrap().data());
+    }
+
+    #[test]
+    #[should_panic(expected = "ecc_len must be less than polynomial capacity")]
+    fn ecc_too_long() {
+        Encoder::with_field(Capacity::<_, 16>(Gf2_8), 16);
+    }
+
+    #[test]
+    #[should_panic(expected = "ecc_len must be less than polynomial capacity")]
+    fn decoder_ecc_too_long() {
+        Decoder::with_field(Capacity::<_, 16>(Gf2_8), 16);
+    }
+}
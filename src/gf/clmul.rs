@@ -0,0 +1,363 @@
+use ::gf::Field;
+
+/// Maximum length of polynomials over wide fields
+const WIDE_POLYNOMIAL_MAX_LENGTH: usize = 1024;
+
+/// Reduction constants of GF(2^n) with polynomial `x^n + poly`, n <= 64
+struct Modulus {
+    degree: u32,
+    /// Field polynomial without the leading term
+    poly: u64,
+    /// Barrett constant `x^2n / (x^n + poly)` without the leading term
+    mu: u64,
+}
+
+impl Modulus {
+    const fn new(degree: u32, poly: u64) -> Modulus {
+        // x^2n / P == x^n + x^n * poly / P, since x^2n == x^n * P + x^n * poly
+        let full = (1u128 << degree) | poly as u128;
+        let mut rem = (poly as u128) << degree;
+        let mut mu = 0u64;
+        let mut i = 2 * degree;
+        while i >= degree {
+            if i < 128 && rem & (1u128 << i) != 0 {
+                mu |= 1 << (i - degree);
+                rem ^= full << (i - degree);
+            }
+            if i == 0 {
+                break;
+            }
+            i -= 1;
+        }
+        Modulus { degree, poly, mu }
+    }
+
+    #[inline]
+    fn mask(&self) -> u64 {
+        if self.degree == 64 { !0 } else { (1 << self.degree) - 1 }
+    }
+
+    /// Barrett reduction of a product of two reduced elements
+    #[inline]
+    fn reduce(&self, c: u128) -> u64 {
+        let hi = (c >> self.degree) as u64;
+        let lo = c as u64 & self.mask();
+        // Quotient hi * (x^n + mu) / x^n
+        let q = (clmul(hi, self.mu) >> self.degree) as u64 ^ hi;
+        lo ^ (clmul(q, self.poly) as u64 & self.mask())
+    }
+
+    #[inline]
+    fn mul(&self, x: u64, y: u64) -> u64 {
+        self.reduce(clmul(x, y))
+    }
+
+    /// Raises `x` to the `power` modulo the group order 2^n - 1
+    #[inline]
+    fn pow(&self, x: u64, power: i32) -> u64 {
+        if x == 0 {
+            return if power == 0 { 1 } else { 0 };
+        }
+
+        let n = self.mask() as u128;
+        let mut e = power.unsigned_abs() as u128 % n;
+        if power < 0 && e != 0 {
+            e = n - e;
+        }
+        self.pow_u64(x, e as u64)
+    }
+
+    #[inline]
+    fn pow_u64(&self, mut x: u64, mut e: u64) -> u64 {
+        let mut acc = 1;
+        while e != 0 {
+            if e & 1 != 0 {
+                acc = self.mul(acc, x);
+            }
+            x = self.mul(x, x);
+            e >>= 1;
+        }
+        acc
+    }
+
+    #[inline]
+    fn inverse(&self, x: u64) -> u64 {
+        debug_assert!(x != 0);
+        // x^(2^n - 1) == 1
+        self.pow_u64(x, self.mask() - 1)
+    }
+}
+
+/// Carry-less multiplication, with PCLMULQDQ when the CPU supports it
+#[inline]
+fn clmul(x: u64, y: u64) -> u128 {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if has_pclmulqdq() {
+            return unsafe { clmul_pclmulqdq(x, y) };
+        }
+    }
+
+    clmul_soft(x, y)
+}
+
+/// Portable carry-less multiplication
+#[inline]
+fn clmul_soft(x: u64, y: u64) -> u128 {
+    let x = x as u128;
+    let mut r = 0u128;
+    for i in 0..64 {
+        // All ones if bit `i` of `y` is set
+        let mask = 0u128.wrapping_sub((y >> i & 1) as u128);
+        r ^= (x << i) & mask;
+    }
+    r
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "pclmulqdq")]
+unsafe fn clmul_pclmulqdq(x: u64, y: u64) -> u128 {
+    use core::arch::x86_64::{_mm_clmulepi64_si128, _mm_set_epi64x, __m128i};
+    use core::mem;
+
+    let r = _mm_clmulepi64_si128(_mm_set_epi64x(0, x as i64), _mm_set_epi64x(0, y as i64), 0);
+    mem::transmute::<__m128i, u128>(r)
+}
+
+/// Runtime PCLMULQDQ detection, cached after the first call
+#[cfg(target_arch = "x86_64")]
+#[inline]
+fn has_pclmulqdq() -> bool {
+    use core::sync::atomic::{AtomicU8, Ordering};
+
+    const UNKNOWN: u8 = 0;
+    const ABSENT: u8 = 1;
+    const PRESENT: u8 = 2;
+    static DETECTED: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+    if cfg!(target_feature = "pclmulqdq") {
+        return true;
+    }
+
+    match DETECTED.load(Ordering::Relaxed) {
+        ABSENT => false,
+        PRESENT => true,
+        _ => {
+            #[allow(unused_unsafe)]
+            let found = unsafe { core::arch::x86_64::__cpuid(1) }.ecx & (1 << 1) != 0;
+            DETECTED.store(if found { PRESENT } else { ABSENT }, Ordering::Relaxed);
+            found
+        }
+    }
+}
+
+macro_rules! wide_field {
+    ($name:ident, $elem:ty, $modulus:ident) => {
+        // Casts are trivial for u64
+        #[allow(trivial_numeric_casts)]
+        impl Field for $name {
+            type Elem = $elem;
+            type Array = [$elem; WIDE_POLYNOMIAL_MAX_LENGTH];
+
+            const ZEROS: Self::Array = [0; WIDE_POLYNOMIAL_MAX_LENGTH];
+
+            #[inline]
+            fn order(&self) -> usize {
+                1usize.checked_shl($modulus.degree).unwrap_or(usize::MAX)
+            }
+
+            #[inline]
+            fn contains(&self, _x: $elem) -> bool {
+                true
+            }
+
+            #[inline]
+            fn primitive(&self) -> $elem {
+                2
+            }
+
+            #[inline]
+            fn add(&self, x: $elem, y: $elem) -> $elem {
+                x ^ y
+            }
+
+            #[inline]
+            fn sub(&self, x: $elem, y: $elem) -> $elem {
+                x ^ y
+            }
+
+            #[inline]
+            fn mul(&self, x: $elem, y: $elem) -> $elem {
+                $modulus.mul(x as u64, y as u64) as $elem
+            }
+
+            #[inline]
+            fn div(&self, x: $elem, y: $elem) -> $elem {
+                debug_assert!(y != 0);
+                self.mul(x, self.inverse(y))
+            }
+
+            #[inline]
+            fn pow(&self, x: $elem, power: i32) -> $elem {
+                $modulus.pow(x as u64, power) as $elem
+            }
+
+            #[inline]
+            fn inverse(&self, x: $elem) -> $elem {
+                $modulus.inverse(x as u64) as $elem
+            }
+        }
+    }
+}
+
+/// x^32 + x^22 + x^2 + x + 1
+static GF2_32: Modulus = Modulus::new(32, 0x0040_0007);
+
+/// x^64 + x^4 + x^3 + x + 1
+static GF2_64: Modulus = Modulus::new(64, 0x1b);
+
+/// GF(2^32) with x^32 + x^22 + x^2 + x + 1 primitive polynomial and generator 2.
+///
+/// Too wide for log/antilog tables: multiplication is carry-less multiplication followed by
+/// Barrett reduction. On x86_64 the PCLMULQDQ instruction is used if the CPU supports it,
+/// which is detected at runtime; otherwise a portable software multiplication is used.
+///
+/// Every `u32` is a valid symbol. Codewords are at most 1024 symbols long.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::{Encoder, Decoder, Gf2_32};
+///
+/// let encoder = Encoder::with_field(Gf2_32, 4);
+/// let decoder = Decoder::with_field(Gf2_32, 4);
+///
+/// let mut encoded = encoder.encode(&[0xdeadbeef, 0xffffffff, 0, 42]);
+/// encoded[1] = 0;
+///
+/// assert_eq!(&[0xdeadbeef, 0xffffffff, 0, 42], decoder.correct(&encoded, None).unwrap().data());
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Gf2_32;
+
+wide_field!(Gf2_32, u32, GF2_32);
+
+/// GF(2^64) with x^64 + x^4 + x^3 + x + 1 primitive polynomial and generator 2.
+///
+/// Same carry-less multiplication backend as `Gf2_32`. Every `u64` is a valid symbol.
+/// Codewords are at most 1024 symbols long.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Gf2_64;
+
+wide_field!(Gf2_64, u64, GF2_64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+    use ::{Encoder, Decoder};
+
+    /// Shift-and-add multiplication modulo `x^n + poly`
+    fn mul_reference(mut x: u64, mut y: u64, degree: u32, poly: u64) -> u64 {
+        let mut r = 0;
+        while y != 0 {
+            if y & 1 != 0 {
+                r ^= x;
+            }
+            let carry = x >> (degree - 1) & 1 != 0;
+            x <<= 1;
+            if degree < 64 {
+                x &= (1 << degree) - 1;
+            }
+            if carry {
+                x ^= poly;
+            }
+            y >>= 1;
+        }
+        r
+    }
+
+    /// xorshift64
+    fn samples() -> Vec<u64> {
+        let mut x = 0x2545f4914f6cdd1du64;
+        let mut samples = Vec::new();
+        samples.extend_from_slice(&[0, 1, 2, 3, !0, 1 << 63, 1 << 31, 0xffff_ffff]);
+        for _ in 0..200 {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            samples.push(x);
+        }
+        samples
+    }
+
+    #[test]
+    fn clmul_matches_software() {
+        let samples = samples();
+        for &x in &samples {
+            for &y in &samples {
+                assert_eq!(clmul_soft(x, y), clmul(x, y));
+                assert_eq!(clmul_soft(x, y), clmul_soft(y, x));
+            }
+        }
+    }
+
+    #[test]
+    fn barrett_reduction() {
+        let samples = samples();
+        for &x in &samples {
+            for &y in &samples {
+                assert_eq!(mul_reference(x, y, 64, 0x1b), GF2_64.mul(x, y));
+                let (x, y) = (x as u32 as u64, y as u32 as u64);
+                assert_eq!(mul_reference(x, y, 32, 0x0040_0007), GF2_32.mul(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        for &x in samples().iter().filter(|&&x| x != 0) {
+            assert_eq!(1, Gf2_64.mul(x, Gf2_64.inverse(x)));
+            assert_eq!(Gf2_64.inverse(x), Gf2_64.pow(x, -1));
+            assert_eq!(Gf2_64.mul(x, x), Gf2_64.pow(x, 2));
+
+            let x = x as u32;
+            if x != 0 {
+                assert_eq!(1, Gf2_32.mul(x, Gf2_32.inverse(x)));
+                assert_eq!(x, Gf2_32.div(Gf2_32.mul(x, 0x1234_5678), 0x1234_5678));
+            }
+        }
+
+        // Generator must have order 2^n - 1: no proper divisor of it is a power giving 1
+        for &q in &[3u64, 5, 17, 257, 65537] {
+            assert!(GF2_32.pow_u64(2, 0xffff_ffff / q) != 1);
+        }
+        for &q in &[3u64, 5, 17, 257, 641, 65537, 6700417] {
+            assert!(GF2_64.pow_u64(2, !0 / q) != 1);
+        }
+        assert_eq!(1, GF2_64.pow_u64(2, !0));
+    }
+
+    #[test]
+    fn errors_and_erasures() {
+        let ecc_len = 12;
+        let encoder = Encoder::with_field(Gf2_64, ecc_len);
+        let decoder = Decoder::with_field(Gf2_64, ecc_len);
+
+        let data = samples();
+        let encoded = encoder.encode(&data);
+        assert!(!decoder.is_corrupted(&encoded));
+
+        let mut corrupted = encoded;
+        for &pos in &[0usize, 100, 207, 210] {
+            corrupted[pos] ^= 0xdead_beef_0000_0001;
+        }
+        let erasures = [3u64, 50, 150, 219];
+        for &pos in erasures.iter() {
+            corrupted[pos as usize] = !0;
+        }
+
+        let (recovered, fixed) = decoder.correct_err_count(&corrupted, Some(&erasures)).unwrap();
+        assert_eq!(&data[..], recovered.data());
+        assert_eq!(8, fixed);
+    }
+}
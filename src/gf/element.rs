@@ -0,0 +1,255 @@
+use core::fmt;
+use core::ops;
+use ::gf;
+
+/// Arithmetic error
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ArithmeticError {
+    /// Division or inversion of zero
+    DivisionByZero,
+    /// Logarithm of zero
+    LogOfZero,
+}
+
+/// Element of the default GF(2^8) field: 0x11d primitive polynomial, generator 2.
+///
+/// Operators map to field arithmetic: addition and subtraction are XOR,
+/// multiplication and division are modulo the field polynomial.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::{Gf256, ArithmeticError};
+///
+/// let x = Gf256(0x53);
+/// let y = Gf256(0xca);
+///
+/// assert_eq!(Gf256(0x99), x + y);
+/// assert_eq!(x, x * y / y);
+/// assert_eq!(Gf256(1), x * x.inverse().unwrap());
+/// assert_eq!(x, Gf256::exp(x.log().unwrap()));
+///
+/// assert_eq!(Err(ArithmeticError::DivisionByZero), x.checked_div(Gf256(0)));
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Gf256(pub u8);
+
+impl Gf256 {
+    /// Additive identity
+    pub const ZERO: Gf256 = Gf256(0);
+    /// Multiplicative identity
+    pub const ONE: Gf256 = Gf256(1);
+    /// Field generator
+    pub const GENERATOR: Gf256 = Gf256(2);
+
+    /// Generator raised to the `power`
+    #[inline]
+    pub fn exp(power: u8) -> Gf256 {
+        Gf256(gf::EXP[power as usize])
+    }
+
+    /// Discrete logarithm to the base of generator, in range `0..255`
+    #[inline]
+    pub fn log(self) -> Result<u8, ArithmeticError> {
+        if self.0 == 0 {
+            Err(ArithmeticError::LogOfZero)
+        } else {
+            Ok(gf::LOG[self.0 as usize])
+        }
+    }
+
+    /// Raises to the `power`, negative powers are powers of the inverse.
+    /// Zero raised to any non-zero power is zero.
+    #[inline]
+    pub fn pow(self, power: i32) -> Gf256 {
+        if self.0 == 0 {
+            return if power == 0 { Gf256::ONE } else { Gf256::ZERO };
+        }
+        Gf256(gf::pow(self.0, power))
+    }
+
+    /// Multiplicative inverse
+    #[inline]
+    pub fn inverse(self) -> Result<Gf256, ArithmeticError> {
+        if self.0 == 0 {
+            Err(ArithmeticError::DivisionByZero)
+        } else {
+            Ok(Gf256(gf::inverse(self.0)))
+        }
+    }
+
+    /// Division that fails instead of panicking when `rhs` is zero
+    #[inline]
+    pub fn checked_div(self, rhs: Gf256) -> Result<Gf256, ArithmeticError> {
+        gf::checked_div(self.0, rhs.0)
+            .map(Gf256)
+            .ok_or(ArithmeticError::DivisionByZero)
+    }
+}
+
+impl From<u8> for Gf256 {
+    #[inline]
+    fn from(x: u8) -> Gf256 {
+        Gf256(x)
+    }
+}
+
+impl From<Gf256> for u8 {
+    #[inline]
+    fn from(x: Gf256) -> u8 {
+        x.0
+    }
+}
+
+impl ops::Add for Gf256 {
+    type Output = Gf256;
+
+    #[inline]
+    fn add(self, rhs: Gf256) -> Gf256 {
+        Gf256(gf::add(self.0, rhs.0))
+    }
+}
+
+impl ops::Sub for Gf256 {
+    type Output = Gf256;
+
+    #[inline]
+    fn sub(self, rhs: Gf256) -> Gf256 {
+        Gf256(gf::sub(self.0, rhs.0))
+    }
+}
+
+impl ops::Mul for Gf256 {
+    type Output = Gf256;
+
+    #[inline]
+    fn mul(self, rhs: Gf256) -> Gf256 {
+        Gf256(gf::mul(self.0, rhs.0))
+    }
+}
+
+impl ops::Div for Gf256 {
+    type Output = Gf256;
+
+    /// # Panics
+    /// Panics if `rhs` is zero, use `checked_div` to handle it.
+    #[inline]
+    fn div(self, rhs: Gf256) -> Gf256 {
+        match self.checked_div(rhs) {
+            Ok(x) => x,
+            Err(_) => panic!("attempt to divide by zero"),
+        }
+    }
+}
+
+impl ops::Neg for Gf256 {
+    type Output = Gf256;
+
+    /// Every element is its own additive inverse in characteristic 2
+    #[inline]
+    fn neg(self) -> Gf256 {
+        self
+    }
+}
+
+impl ops::AddAssign for Gf256 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Gf256) {
+        *self = *self + rhs;
+    }
+}
+
+impl ops::SubAssign for Gf256 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Gf256) {
+        *self = *self - rhs;
+    }
+}
+
+impl ops::MulAssign for Gf256 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Gf256) {
+        *self = *self * rhs;
+    }
+}
+
+impl ops::DivAssign for Gf256 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Gf256) {
+        *self = *self / rhs;
+    }
+}
+
+impl fmt::Display for Gf256 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fmt)
+    }
+}
+
+impl fmt::LowerHex for Gf256 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, fmt)
+    }
+}
+
+impl fmt::UpperHex for Gf256 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, fmt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::format;
+
+    #[test]
+    fn operators() {
+        for x in 0..256usize {
+            let x = Gf256(x as u8);
+            assert_eq!(Gf256::ZERO, x + x);
+            assert_eq!(Gf256::ZERO, x + (-x));
+            assert_eq!(x, x * Gf256::ONE);
+            assert_eq!(x.pow(2), x * x);
+
+            if x != Gf256::ZERO {
+                let inv = x.inverse().unwrap();
+                assert_eq!(Gf256::ONE, x * inv);
+                assert_eq!(inv, x.pow(-1));
+                assert_eq!(inv, Gf256::ONE / x);
+                assert_eq!(x, Gf256::exp(x.log().unwrap()));
+            }
+
+            let mut y = x;
+            y *= Gf256(0x1d);
+            y /= Gf256(0x1d);
+            y += Gf256(7);
+            y -= Gf256(7);
+            assert_eq!(x, y);
+        }
+        assert_eq!(Gf256(0x1d), Gf256(0x80) * Gf256::GENERATOR);
+        assert_eq!(Gf256::ONE, Gf256::GENERATOR.pow(255));
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(Err(ArithmeticError::DivisionByZero), Gf256(3).checked_div(Gf256::ZERO));
+        assert_eq!(Err(ArithmeticError::DivisionByZero), Gf256::ZERO.inverse());
+        assert_eq!(Err(ArithmeticError::LogOfZero), Gf256::ZERO.log());
+        assert_eq!(Ok(Gf256::ZERO), Gf256::ZERO.checked_div(Gf256(3)));
+        assert_eq!(Gf256::ONE, Gf256::ZERO.pow(0));
+        assert_eq!(Gf256::ZERO, Gf256::ZERO.pow(5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_by_zero() {
+        let _ = Gf256(1) / Gf256(0);
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!("29", format!("{}", Gf256(0x1d)));
+        assert_eq!("1d", format!("{:x}", Gf256(0x1d)));
+        assert_eq!("Gf256(29)", format!("{:?}", Gf256(0x1d)));
+    }
+}
@@ -0,0 +1,265 @@
+use core::fmt;
+use ::gf;
+use ::gf::poly::Storage;
+
+/// Unsigned integer type that stores field elements
+pub trait Symbol: Copy + Eq + Default + fmt::Debug {
+    /// Additive identity
+    const ZERO: Self;
+    /// Multiplicative identity
+    const ONE: Self;
+
+    /// Converts `usize` into a symbol, truncating high bits
+    fn from_usize(x: usize) -> Self;
+
+    /// Converts symbol into `usize`
+    fn to_usize(self) -> usize;
+}
+
+macro_rules! impl_symbol {
+    ($($t:ty),*) => {$(
+        impl Symbol for $t {
+            const ZERO: $t = 0;
+            const ONE: $t = 1;
+
+            #[inline]
+            fn from_usize(x: usize) -> $t {
+                x as $t
+            }
+
+            #[inline]
+            fn to_usize(self) -> usize {
+                self as usize
+            }
+        }
+    )*}
+}
+
+impl_symbol!(u8, u16, u32, u64);
+
+/// Finite field over which Reed-Solomon codes are built
+pub trait Field {
+    /// Field element type
+    type Elem: Symbol;
+
+    /// Backing storage of polynomials over the field, usually a fixed-size array.
+    /// Its capacity bounds the length of a codeword.
+    type Array: Storage<Self::Elem>;
+
+    /// Empty or zero-filled `Array`
+    const ZEROS: Self::Array;
+
+    /// Number of elements in the field, saturated to `usize::MAX` for fields too wide for `usize`
+    fn order(&self) -> usize;
+
+    /// Checks that `x` is an element of the field
+    #[inline]
+    fn contains(&self, x: Self::Elem) -> bool {
+        x.to_usize() < self.order()
+    }
+
+    /// Primitive element, generator of the field's multiplicative group
+    fn primitive(&self) -> Self::Elem;
+
+    /// Field addition
+    fn add(&self, x: Self::Elem, y: Self::Elem) -> Self::Elem;
+
+    /// Field subtraction
+    fn sub(&self, x: Self::Elem, y: Self::Elem) -> Self::Elem;
+
+    /// Additive inverse
+    #[inline]
+    fn neg(&self, x: Self::Elem) -> Self::Elem {
+        self.sub(Self::Elem::ZERO, x)
+    }
+
+    /// Field multiplication
+    fn mul(&self, x: Self::Elem, y: Self::Elem) -> Self::Elem;
+
+    /// Field division, `y` must not be zero
+    fn div(&self, x: Self::Elem, y: Self::Elem) -> Self::Elem;
+
+    /// Raises `x` to the `power`, negative powers are powers of the inverse
+    fn pow(&self, x: Self::Elem, power: i32) -> Self::Elem;
+
+    /// Multiplicative inverse, `x` must not be zero
+    fn inverse(&self, x: Self::Elem) -> Self::Elem;
+
+    /// Adds `c * src[i]` to `dst[i]` for every `i` below the length of the shorter slice.
+    ///
+    /// This is the inner loop of encoding and syndrome computation,
+    /// fields may override it with vectorized kernels.
+    #[inline]
+    fn mul_add_slice(&self, c: Self::Elem, src: &[Self::Elem], dst: &mut [Self::Elem]) {
+        for (d, s) in dst.iter_mut().zip(src.iter()) {
+            *d = self.add(*d, self.mul(c, *s));
+        }
+    }
+}
+
+/// Field whose arithmetic neither branches on nor indexes memory by element values,
+/// so that its running time doesn't depend on the data.
+///
+/// `inverse(0)` and `div(x, 0)` must return zero rather than panic,
+/// `pow` may branch on the power only.
+pub trait ConstantTime: Field {}
+
+impl<F: ConstantTime + ?Sized> ConstantTime for &F {}
+
+/// Field construction error
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FieldError {
+    /// Polynomial degree doesn't match the field size
+    InvalidDegree,
+    /// Polynomial is reducible and doesn't define a field
+    Reducible,
+    /// Generator doesn't generate all non-zero elements of the field
+    NotPrimitive,
+    /// Characteristic of a prime field is not a prime number
+    NotPrime,
+}
+
+impl<F: Field + ?Sized> Field for &F {
+    type Elem = F::Elem;
+    type Array = F::Array;
+
+    const ZEROS: Self::Array = F::ZEROS;
+
+    #[inline]
+    fn order(&self) -> usize {
+        (**self).order()
+    }
+
+    #[inline]
+    fn contains(&self, x: Self::Elem) -> bool {
+        (**self).contains(x)
+    }
+
+    #[inline]
+    fn primitive(&self) -> Self::Elem {
+        (**self).primitive()
+    }
+
+    #[inline]
+    fn add(&self, x: Self::Elem, y: Self::Elem) -> Self::Elem {
+        (**self).add(x, y)
+    }
+
+    #[inline]
+    fn sub(&self, x: Self::Elem, y: Self::Elem) -> Self::Elem {
+        (**self).sub(x, y)
+    }
+
+    #[inline]
+    fn neg(&self, x: Self::Elem) -> Self::Elem {
+        (**self).neg(x)
+    }
+
+    #[inline]
+    fn mul(&self, x: Self::Elem, y: Self::Elem) -> Self::Elem {
+        (**self).mul(x, y)
+    }
+
+    #[inline]
+    fn div(&self, x: Self::Elem, y: Self::Elem) -> Self::Elem {
+        (**self).div(x, y)
+    }
+
+    #[inline]
+    fn pow(&self, x: Self::Elem, power: i32) -> Self::Elem {
+        (**self).pow(x, power)
+    }
+
+    #[inline]
+    fn inverse(&self, x: Self::Elem) -> Self::Elem {
+        (**self).inverse(x)
+    }
+
+    #[inline]
+    fn mul_add_slice(&self, c: Self::Elem, src: &[Self::Elem], dst: &mut [Self::Elem]) {
+        (**self).mul_add_slice(c, src, dst)
+    }
+}
+
+/// GF(2^8) with 0x11d primitive polynomial and generator 2, backed by pre-calculated tables
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Gf2_8;
+
+impl Field for Gf2_8 {
+    type Elem = u8;
+    type Array = [u8; ::POLYNOMIAL_MAX_LENGTH];
+
+    const ZEROS: Self::Array = [0; ::POLYNOMIAL_MAX_LENGTH];
+
+    #[inline]
+    fn order(&self) -> usize {
+        256
+    }
+
+    #[inline]
+    fn primitive(&self) -> u8 {
+        2
+    }
+
+    #[inline]
+    fn add(&self, x: u8, y: u8) -> u8 {
+        gf::add(x, y)
+    }
+
+    #[inline]
+    fn sub(&self, x: u8, y: u8) -> u8 {
+        gf::sub(x, y)
+    }
+
+    #[inline]
+    fn mul(&self, x: u8, y: u8) -> u8 {
+        gf::mul(x, y)
+    }
+
+    #[inline]
+    fn div(&self, x: u8, y: u8) -> u8 {
+        gf::div(x, y)
+    }
+
+    #[inline]
+    fn pow(&self, x: u8, power: i32) -> u8 {
+        gf::pow(x, power)
+    }
+
+    #[inline]
+    fn inverse(&self, x: u8) -> u8 {
+        gf::inverse(x)
+    }
+
+    #[inline]
+    fn mul_add_slice(&self, c: u8, src: &[u8], dst: &mut [u8]) {
+        gf::simd::mul_add_with(c, src, dst, gf::mul)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gf2_8_matches_tables() {
+        let field = Gf2_8;
+        for x in 0..256usize {
+            let x = x as u8;
+            assert_eq!(field.mul(x, 3), gf::mul(x, 3));
+            assert_eq!(field.pow(x, 7), gf::pow(x, 7));
+            if x != 0 {
+                assert_eq!(field.mul(x, field.inverse(x)), 1);
+                assert_eq!(field.div(x, x), 1);
+            }
+        }
+        assert_eq!(field.pow(field.primitive(), 255), 1);
+
+        let src: [u8; 40] = core::array::from_fn(|i| (i * 7) as u8);
+        let mut dst = [3; 40];
+        field.mul_add_slice(0x53, &src, &mut dst);
+        for i in 0..40 {
+            assert_eq!(3 ^ gf::mul(0x53, src[i]), dst[i]);
+        }
+    }
+}
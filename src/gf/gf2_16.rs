@@ -0,0 +1,232 @@
+use ::gf::Field;
+
+const POLY: u32 = 0x1100b;
+const ORDER: usize = 65536;
+const LOG_SIZE: usize = ORDER;
+const EXP_SIZE: usize = 2 * (ORDER - 1);
+
+/// Maximum length of polynomials over GF(2^16) without the `alloc` feature
+#[cfg(not(feature = "alloc"))]
+const GF2_16_POLYNOMIAL_MAX_LENGTH: usize = 4096;
+
+pub static EXP: [u16; EXP_SIZE] = exp_table();
+pub static LOG: [u16; LOG_SIZE] = log_table();
+
+const fn exp_table() -> [u16; EXP_SIZE] {
+    let mut exp = [0; EXP_SIZE];
+    let mut x = 1u32;
+    let mut i = 0;
+    while i < ORDER - 1 {
+        exp[i] = x as u16;
+        exp[i + ORDER - 1] = x as u16;
+        x <<= 1;
+        if x & 0x10000 != 0 {
+            x ^= POLY;
+        }
+        i += 1;
+    }
+    exp
+}
+
+const fn log_table() -> [u16; LOG_SIZE] {
+    let mut log = [0; LOG_SIZE];
+    let mut x = 1u32;
+    let mut i = 0;
+    while i < ORDER - 1 {
+        log[x as usize] = i as u16;
+        x <<= 1;
+        if x & 0x10000 != 0 {
+            x ^= POLY;
+        }
+        i += 1;
+    }
+    log
+}
+
+/// GF(2^16) with 0x1100b primitive polynomial and generator 2, for codewords up to 65535 symbols.
+///
+/// Exponent and logarithm tables are generated at compile time.
+///
+/// With the `alloc` feature polynomials over this field are `Vec`-backed, like with `Heap`,
+/// and codewords may be up to 65535 symbols long. Without it they are inline arrays
+/// of 4096 symbols (8 KiB), so that decoding fits into the stack of any thread, and longer
+/// codewords are rejected: `Encoder::try_encode` fails with `EncoderError::TooLong`
+/// and `Decoder` with `DecoderError::TooLong`.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::{Encoder, Decoder, Gf2_16};
+///
+/// let encoder = Encoder::with_field(Gf2_16, 8);
+/// let decoder = Decoder::with_field(Gf2_16, 8);
+///
+/// let data: Vec<u16> = (0..1000).collect();
+/// let mut encoded = encoder.encode(&data);
+/// encoded[300] = 0;
+/// encoded[700] = 0;
+///
+/// let recovered = decoder.correct(&encoded, Some(&[700])).unwrap();
+/// assert_eq!(&data[..], recovered.data());
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Gf2_16;
+
+impl Field for Gf2_16 {
+    type Elem = u16;
+    #[cfg(feature = "alloc")]
+    type Array = ::alloc::vec::Vec<u16>;
+    #[cfg(not(feature = "alloc"))]
+    type Array = [u16; GF2_16_POLYNOMIAL_MAX_LENGTH];
+
+    #[cfg(feature = "alloc")]
+    const ZEROS: Self::Array = ::alloc::vec::Vec::new();
+    #[cfg(not(feature = "alloc"))]
+    const ZEROS: Self::Array = [0; GF2_16_POLYNOMIAL_MAX_LENGTH];
+
+    #[inline]
+    fn order(&self) -> usize {
+        ORDER
+    }
+
+    #[inline]
+    fn primitive(&self) -> u16 {
+        2
+    }
+
+    #[inline]
+    fn add(&self, x: u16, y: u16) -> u16 {
+        x ^ y
+    }
+
+    #[inline]
+    fn sub(&self, x: u16, y: u16) -> u16 {
+        x ^ y
+    }
+
+    #[inline]
+    fn mul(&self, x: u16, y: u16) -> u16 {
+        if x == 0 || y == 0 {
+            0
+        } else {
+            let exp_index = uncheck!(LOG[x as usize]) as usize + uncheck!(LOG[y as usize]) as usize;
+            uncheck!(EXP[exp_index])
+        }
+    }
+
+    #[inline]
+    fn div(&self, x: u16, y: u16) -> u16 {
+        debug_assert!(y != 0);
+        if x == 0 {
+            0
+        } else {
+            let exp_index = uncheck!(LOG[x as usize]) as usize + (ORDER - 1)
+                            - uncheck!(LOG[y as usize]) as usize;
+            uncheck!(EXP[exp_index])
+        }
+    }
+
+    #[inline]
+    fn pow(&self, x: u16, power: i32) -> u16 {
+        if x == 0 {
+            return if power == 0 { 1 } else { 0 };
+        }
+
+        let n = (ORDER - 1) as i64;
+        let mut i = uncheck!(LOG[x as usize]) as i64 * power as i64 % n;
+        if i < 0 {
+            i += n;
+        }
+
+        uncheck!(EXP[i as usize])
+    }
+
+    #[inline]
+    fn inverse(&self, x: u16) -> u16 {
+        debug_assert!(x != 0);
+        let exp_index = (ORDER - 1) - uncheck!(LOG[x as usize]) as usize;
+        uncheck!(EXP[exp_index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+    use ::{Encoder, Decoder};
+
+    #[test]
+    fn tables() {
+        // Generator must visit every non-zero element once
+        let mut seen = [false; ORDER];
+        for (i, x) in EXP[..ORDER - 1].iter().enumerate() {
+            let x = *x as usize;
+            assert!(x != 0 && !seen[x]);
+            seen[x] = true;
+            assert_eq!(i, LOG[x] as usize);
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        let field = Gf2_16;
+        for &x in &[1u16, 2, 3, 0x100, 0x8000, 0xffff, 12345] {
+            assert_eq!(1, field.mul(x, field.inverse(x)));
+            assert_eq!(x, field.div(field.mul(x, 0x1234), 0x1234));
+            assert_eq!(field.mul(x, x), field.pow(x, 2));
+            assert_eq!(field.inverse(x), field.pow(x, -1));
+        }
+        assert_eq!(0x100b, field.mul(0x8000, 2));
+    }
+
+    #[test]
+    fn long_codeword() {
+        let ecc_len = 16;
+        let encoder = Encoder::with_field(Gf2_16, ecc_len);
+        let decoder = Decoder::with_field(Gf2_16, ecc_len);
+
+        let data: Vec<u16> = (0..4000u32).map(|x| (x * 7919) as u16).collect();
+        let encoded = encoder.encode(&data);
+        assert_eq!(4000 + ecc_len, encoded.len());
+        assert!(!decoder.is_corrupted(&encoded));
+
+        let mut corrupted = encoded[..].to_vec();
+        for &pos in &[0usize, 299, 1000, 2047, 3999, 4010] {
+            corrupted[pos] ^= 0xa5a5;
+        }
+        let erasures = [256u16, 3000, 3500, 4005];
+        for &pos in erasures.iter() {
+            corrupted[pos as usize] = 0;
+        }
+
+        let (recovered, fixed) = decoder.correct_err_count(&corrupted, Some(&erasures)).unwrap();
+        assert_eq!(&data[..], recovered.data());
+        assert_eq!(&encoded[..], &recovered[..]);
+        assert_eq!(10, fixed);
+    }
+
+    #[test]
+    fn longest_codeword() {
+        use ::{EncoderError, DecoderError};
+
+        let ecc_len = 8;
+        let encoder = Encoder::with_field(Gf2_16, ecc_len);
+        let decoder = Decoder::with_field(Gf2_16, ecc_len);
+        let data: Vec<u16> = (0..65535 - ecc_len as u32).map(|x| (x * 31) as u16).collect();
+
+        if cfg!(feature = "alloc") {
+            let mut corrupted = encoder.encode(&data);
+            corrupted[40000] ^= 1;
+            assert_eq!(&data[..], decoder.correct(&corrupted, None).unwrap().data());
+        } else {
+            assert_eq!(Some(EncoderError::TooLong), encoder.try_encode(&data).err());
+
+            let mut codeword = data.clone();
+            codeword.resize(data.len() + ecc_len, 0);
+            encoder.encode_in_place(&mut codeword).unwrap();
+            match decoder.correct(&codeword, None) {
+                Err(DecoderError::TooLong) => (),
+                _ => panic!("codeword longer than polynomial capacity accepted"),
+            }
+        }
+    }
+}
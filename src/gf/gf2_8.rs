@@ -0,0 +1,391 @@
+use ::gf::{Field, ConstantTime, simd};
+
+const POLY: u16 = 0x11d;
+
+/// Carry-less multiplication modulo 0x11d, one bit of `y` at a time
+pub(super) const fn mul_bitwise(x: u8, y: u8) -> u8 {
+    let mut x = x as u16;
+    let mut y = y;
+    let mut r = 0u16;
+    while y != 0 {
+        if y & 1 != 0 {
+            r ^= x;
+        }
+        x <<= 1;
+        if x & 0x100 != 0 {
+            x ^= POLY;
+        }
+        y >>= 1;
+    }
+    r as u8
+}
+
+/// Square-and-multiply, `x` to the power of `power` modulo 255
+#[inline]
+fn pow_with<M: Fn(u8, u8) -> u8>(x: u8, power: i32, mul: M) -> u8 {
+    if x == 0 {
+        return if power == 0 { 1 } else { 0 };
+    }
+
+    let mut e = power % 255;
+    if e < 0 {
+        e += 255;
+    }
+
+    let mut base = x;
+    let mut acc = 1;
+    while e != 0 {
+        if e & 1 != 0 {
+            acc = mul(acc, base);
+        }
+        base = mul(base, base);
+        e >>= 1;
+    }
+    acc
+}
+
+macro_rules! binary_field_ops {
+    () => {
+        #[inline]
+        fn order(&self) -> usize {
+            256
+        }
+
+        #[inline]
+        fn primitive(&self) -> u8 {
+            2
+        }
+
+        #[inline]
+        fn add(&self, x: u8, y: u8) -> u8 {
+            x ^ y
+        }
+
+        #[inline]
+        fn sub(&self, x: u8, y: u8) -> u8 {
+            x ^ y
+        }
+
+        #[inline]
+        fn div(&self, x: u8, y: u8) -> u8 {
+            debug_assert!(y != 0);
+            self.mul(x, self.inverse(y))
+        }
+
+        #[inline]
+        fn pow(&self, x: u8, power: i32) -> u8 {
+            pow_with(x, power, |x, y| self.mul(x, y))
+        }
+    }
+}
+
+static PRODUCT: [[u8; 256]; 256] = product_table();
+
+const fn product_table() -> [[u8; 256]; 256] {
+    let mut table = [[0; 256]; 256];
+    let mut x = 0;
+    while x < 256 {
+        let mut y = 0;
+        while y < 256 {
+            table[x][y] = mul_bitwise(x as u8, y as u8);
+            y += 1;
+        }
+        x += 1;
+    }
+    table
+}
+
+static INVERSE: [u8; 256] = inverse_table();
+
+const fn inverse_table() -> [u8; 256] {
+    let mut table = [0; 256];
+    let mut x = 1;
+    while x < 256 {
+        let mut y = 1;
+        while mul_bitwise(x as u8, y as u8) != 1 {
+            y += 1;
+        }
+        table[x] = y as u8;
+        x += 1;
+    }
+    table
+}
+
+/// `Gf2_8` backed by a 64 KiB table of all products, with a single lookup per multiplication.
+///
+/// Fastest backend when the table fits in cache, a good fit for servers.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Gf2_8Product;
+
+impl Field for Gf2_8Product {
+    type Elem = u8;
+    type Array = [u8; ::POLYNOMIAL_MAX_LENGTH];
+
+    const ZEROS: Self::Array = [0; ::POLYNOMIAL_MAX_LENGTH];
+
+    binary_field_ops!();
+
+    #[inline]
+    fn mul(&self, x: u8, y: u8) -> u8 {
+        let row = &PRODUCT[x as usize];
+        uncheck!(row[y as usize])
+    }
+
+    #[inline]
+    fn inverse(&self, x: u8) -> u8 {
+        debug_assert!(x != 0);
+        uncheck!(INVERSE[x as usize])
+    }
+
+    #[inline]
+    fn mul_add_slice(&self, c: u8, src: &[u8], dst: &mut [u8]) {
+        simd::mul_add_with(c, src, dst, |x, y| self.mul(x, y))
+    }
+}
+
+/// Products of every element with every 4-bit value: `LOW[y][n] = y * n`, `HIGH[y][n] = y * (n << 4)`
+pub(super) static LOW: [[u8; 16]; 256] = nibble_table(0);
+pub(super) static HIGH: [[u8; 16]; 256] = nibble_table(4);
+
+const fn nibble_table(shift: u32) -> [[u8; 16]; 256] {
+    let mut table = [[0; 16]; 256];
+    let mut y = 0;
+    while y < 256 {
+        let mut n = 0;
+        while n < 16 {
+            table[y][n] = mul_bitwise(y as u8, (n as u8) << shift);
+            n += 1;
+        }
+        y += 1;
+    }
+    table
+}
+
+/// `Gf2_8` backed by two 4 KiB split tables: `x * y` is the sum of the products of `y`
+/// with the low and the high nibble of `x`.
+///
+/// The 16 entries used for a given `y` share a cache line, which makes this backend
+/// cache friendly when one operand is fixed, as with generator coefficients.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Gf2_8Nibble;
+
+impl Field for Gf2_8Nibble {
+    type Elem = u8;
+    type Array = [u8; ::POLYNOMIAL_MAX_LENGTH];
+
+    const ZEROS: Self::Array = [0; ::POLYNOMIAL_MAX_LENGTH];
+
+    binary_field_ops!();
+
+    #[inline]
+    fn mul(&self, x: u8, y: u8) -> u8 {
+        let low = &LOW[y as usize];
+        let high = &HIGH[y as usize];
+        uncheck!(low[(x & 0xf) as usize]) ^ uncheck!(high[(x >> 4) as usize])
+    }
+
+    #[inline]
+    fn inverse(&self, x: u8) -> u8 {
+        debug_assert!(x != 0);
+        // x^255 == 1
+        self.pow(x, 254)
+    }
+
+    #[inline]
+    fn mul_add_slice(&self, c: u8, src: &[u8], dst: &mut [u8]) {
+        simd::mul_add_with(c, src, dst, |x, y| self.mul(x, y))
+    }
+}
+
+/// `Gf2_8` without any tables: multiplication is shift-and-reduce,
+/// inverse is computed as `x^254`.
+///
+/// Slowest backend, meant for microcontrollers that cannot spare flash for tables.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Gf2_8Bitwise;
+
+impl Field for Gf2_8Bitwise {
+    type Elem = u8;
+    type Array = [u8; ::POLYNOMIAL_MAX_LENGTH];
+
+    const ZEROS: Self::Array = [0; ::POLYNOMIAL_MAX_LENGTH];
+
+    binary_field_ops!();
+
+    #[inline]
+    fn mul(&self, x: u8, y: u8) -> u8 {
+        mul_bitwise(x, y)
+    }
+
+    #[inline]
+    fn inverse(&self, x: u8) -> u8 {
+        debug_assert!(x != 0);
+        // x^255 == 1
+        self.pow(x, 254)
+    }
+}
+
+/// Branch-free multiplication modulo 0x11d, the loop runs 8 times for any operands
+#[inline]
+fn mul_constant_time(mut x: u8, y: u8) -> u8 {
+    let mut r = 0;
+    for i in 0..8 {
+        // All ones if bit `i` of `y` is set
+        r ^= x & 0u8.wrapping_sub(y >> i & 1);
+        x = (x << 1) ^ (POLY as u8 & 0u8.wrapping_sub(x >> 7));
+    }
+    r
+}
+
+/// `x^e`, branches on bits of the public exponent only
+#[inline]
+fn pow_constant_time(x: u8, e: u8) -> u8 {
+    let mut base = x;
+    let mut acc = 1;
+    for i in 0..8 {
+        if e >> i & 1 != 0 {
+            acc = mul_constant_time(acc, base);
+        }
+        base = mul_constant_time(base, base);
+    }
+    acc
+}
+
+/// `Gf2_8` with constant-time arithmetic: no tables, and no branches on element values.
+///
+/// Multiplication is shift-and-reduce with masks instead of conditions, inverse is `x^254`,
+/// and the inverse of zero is zero. Use it with `Decoder::correct_constant_time`
+/// to decode secret data, e.g. in secret sharing.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Gf2_8ConstantTime;
+
+impl Field for Gf2_8ConstantTime {
+    type Elem = u8;
+    type Array = [u8; ::POLYNOMIAL_MAX_LENGTH];
+
+    const ZEROS: Self::Array = [0; ::POLYNOMIAL_MAX_LENGTH];
+
+    #[inline]
+    fn order(&self) -> usize {
+        256
+    }
+
+    #[inline]
+    fn primitive(&self) -> u8 {
+        2
+    }
+
+    #[inline]
+    fn add(&self, x: u8, y: u8) -> u8 {
+        x ^ y
+    }
+
+    #[inline]
+    fn sub(&self, x: u8, y: u8) -> u8 {
+        x ^ y
+    }
+
+    #[inline]
+    fn mul(&self, x: u8, y: u8) -> u8 {
+        mul_constant_time(x, y)
+    }
+
+    #[inline]
+    fn div(&self, x: u8, y: u8) -> u8 {
+        mul_constant_time(x, self.inverse(y))
+    }
+
+    #[inline]
+    fn pow(&self, x: u8, power: i32) -> u8 {
+        if power == 0 {
+            return 1;
+        }
+
+        let mut e = power % 255;
+        if e < 0 {
+            e += 255;
+        }
+
+        // Zero to a non-zero power is zero, even if the power is a multiple of 255
+        let non_zero = ((x as u16 + 0xff) >> 8) as u8;
+        pow_constant_time(x, e as u8) & 0u8.wrapping_sub(non_zero)
+    }
+
+    #[inline]
+    fn inverse(&self, x: u8) -> u8 {
+        // x^255 == 1, and 0^254 == 0
+        pow_constant_time(x, 254)
+    }
+}
+
+impl ConstantTime for Gf2_8ConstantTime {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::gf::Gf2_8;
+    use ::{Encoder, Decoder};
+
+    fn matches_default<F: Field<Elem = u8>>(field: F) {
+        for x in 0..256usize {
+            let x = x as u8;
+            for y in 0..256usize {
+                let y = y as u8;
+                assert_eq!(Gf2_8.mul(x, y), field.mul(x, y));
+                if y != 0 {
+                    assert_eq!(Gf2_8.div(x, y), field.div(x, y));
+                }
+            }
+            if x != 0 {
+                assert_eq!(Gf2_8.inverse(x), field.inverse(x));
+                for &power in &[-300, -1, 0, 1, 2, 7, 254, 255, 1000] {
+                    assert_eq!(Gf2_8.pow(x, power), field.pow(x, power));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        matches_default(Gf2_8Product);
+        matches_default(Gf2_8Nibble);
+        matches_default(Gf2_8Bitwise);
+        matches_default(Gf2_8ConstantTime);
+
+        assert_eq!(0, Gf2_8ConstantTime.inverse(0));
+        assert_eq!(0, Gf2_8ConstantTime.div(5, 0));
+        assert_eq!(0, Gf2_8ConstantTime.pow(0, 255));
+        assert_eq!(1, Gf2_8ConstantTime.pow(0, 0));
+    }
+
+    fn same_codewords<F: Field<Elem = u8> + Copy>(field: F) {
+        let data: [u8; 32] = [
+            0x40, 0xd2, 0x75, 0x47, 0x76, 0x17, 0x32, 0x06, 0x27, 0x26, 0x96, 0xc6, 0xc6, 0x96,
+            0x70, 0xec, 0x11, 0xec, 0x11, 0xec, 0x11, 0xec, 0x11, 0xec, 0x11, 0xec, 0x11, 0xec,
+            0x11, 0xec, 0x11, 0xec,
+        ];
+        let expected = Encoder::new(10).encode(&data);
+        let encoded = Encoder::with_field(field, 10).encode(&data);
+        assert_eq!(&expected[..], &encoded[..]);
+
+        let mut corrupted = encoded;
+        corrupted[0] = 0;
+        corrupted[17] ^= 0x55;
+        corrupted[40] = 0xff;
+        let erasures = [5];
+        corrupted[5] = 0;
+
+        let decoder = Decoder::with_field(field, 10);
+        let (recovered, fixed) = decoder.correct_err_count(&corrupted, Some(&erasures)).unwrap();
+        assert_eq!(&expected[..], &recovered[..]);
+        assert_eq!(4, fixed);
+    }
+
+    #[test]
+    fn codewords() {
+        same_codewords(Gf2_8Product);
+        same_codewords(Gf2_8Nibble);
+        same_codewords(Gf2_8Bitwise);
+        same_codewords(Gf2_8ConstantTime);
+    }
+}
@@ -0,0 +1,95 @@
+use alloc::vec::Vec;
+use ::gf::{Field, ConstantTime};
+
+/// Field `F` with `Vec`-backed polynomials and buffers.
+///
+/// Arithmetic is delegated to `F`, only the storage differs: polynomials are allocated on the
+/// heap and grow as needed, so codeword length is bounded by the field order alone
+/// and moving a `Buffer` doesn't copy its symbols.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::{Encoder, Decoder, Heap, Gf2_16};
+///
+/// // Buffers are `Vec`-backed and cheap to move
+/// let encoder = Encoder::with_field(Heap(Gf2_16), 8);
+/// let decoder = Decoder::with_field(Heap(Gf2_16), 8);
+///
+/// let data: Vec<u16> = (0..1000).collect();
+/// let mut encoded = encoder.encode(&data);
+/// encoded[300] = 0;
+/// encoded[700] = 0;
+///
+/// let recovered = decoder.correct(&encoded, Some(&[700])).unwrap();
+/// assert_eq!(&data[..], recovered.data());
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Heap<F>(pub F);
+
+impl<F: Field> Field for Heap<F> {
+    type Elem = F::Elem;
+    type Array = Vec<F::Elem>;
+
+    const ZEROS: Self::Array = Vec::new();
+
+    delegate_field_ops!();
+}
+
+impl<F: ConstantTime> ConstantTime for Heap<F> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::gf::{Gf2_8, Gf2_16};
+    use ::gf::poly::Polynom;
+    use ::{Encoder, Decoder};
+
+    #[test]
+    fn polynom_grows() {
+        let mut poly = Polynom::<Heap<Gf2_8>>::new();
+        for i in 0..1000 {
+            poly.push(i as u8);
+        }
+        assert_eq!(1000, poly.len());
+        assert_eq!(usize::MAX, Polynom::<Heap<Gf2_8>>::capacity());
+
+        poly.set_length(10);
+        poly.set_length(20);
+        assert_eq!([0; 10], poly[10..]);
+    }
+
+    #[test]
+    fn same_codewords() {
+        let data: Vec<u8> = (0..200).collect();
+        let expected = Encoder::new(16).encode(&data);
+        let encoded = Encoder::with_field(Heap(Gf2_8), 16).encode(&data);
+        assert_eq!(&expected[..], &encoded[..]);
+
+        let mut corrupted = encoded.clone();
+        for &pos in &[0usize, 10, 100, 150, 210] {
+            corrupted[pos] ^= 0x42;
+        }
+        let decoder = Decoder::with_field(Heap(Gf2_8), 16);
+        let (recovered, fixed) = decoder.correct_err_count(&corrupted, Some(&[20, 30])).unwrap();
+        assert_eq!(&expected[..], &recovered[..]);
+        assert_eq!(7, fixed);
+    }
+
+    #[test]
+    fn long_codeword() {
+        let ecc_len = 32;
+        let encoder = Encoder::with_field(Heap(Gf2_16), ecc_len);
+        let decoder = Decoder::with_field(Heap(Gf2_16), ecc_len);
+
+        let data: Vec<u16> = (0..20000u32).map(|x| (x * 7919) as u16).collect();
+        let encoded = encoder.encode(&data);
+
+        let mut corrupted = encoded.clone();
+        for &pos in &[0usize, 5000, 10000, 19999, 20031] {
+            corrupted[pos] ^= 0x1234;
+        }
+
+        let recovered = decoder.correct(&corrupted, None).unwrap();
+        assert_eq!(&data[..], recovered.data());
+    }
+}
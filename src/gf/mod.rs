@@ -1,66 +1,40 @@
 //! Operations over Galois Fields, using pre-calculated tables for 0x11d primitive polynomial
 pub mod poly;
 pub mod poly_math;
+mod field;
+mod binary;
+mod capacity;
+mod clmul;
+mod element;
+mod gf2_8;
+pub mod gf2_16;
+mod prime;
+mod simd;
+#[cfg(feature = "alloc")]
+mod heap;
+
+pub use self::field::{Symbol, Field, ConstantTime, FieldError, Gf2_8};
+pub use self::binary::BinaryField;
+pub use self::capacity::Capacity;
+pub use self::clmul::{Gf2_32, Gf2_64};
+pub use self::element::{Gf256, ArithmeticError};
+pub use self::gf2_8::{Gf2_8Product, Gf2_8Nibble, Gf2_8Bitwise, Gf2_8ConstantTime};
+pub use self::gf2_16::Gf2_16;
+pub use self::prime::PrimeField;
+#[cfg(feature = "alloc")]
+pub use self::heap::Heap;
 
 const EXP_SIZE: usize = 512;
-pub static EXP: [u8; EXP_SIZE] = [
-    0x1, 0x2, 0x4, 0x8, 0x10, 0x20, 0x40, 0x80, 0x1d, 0x3a, 0x74, 0xe8, 0xcd, 0x87, 0x13, 0x26, 0x4c,
-    0x98, 0x2d, 0x5a, 0xb4, 0x75, 0xea, 0xc9, 0x8f, 0x3, 0x6, 0xc, 0x18, 0x30, 0x60, 0xc0, 0x9d,
-    0x27, 0x4e, 0x9c, 0x25, 0x4a, 0x94, 0x35, 0x6a, 0xd4, 0xb5, 0x77, 0xee, 0xc1, 0x9f, 0x23, 0x46,
-    0x8c, 0x5, 0xa, 0x14, 0x28, 0x50, 0xa0, 0x5d, 0xba, 0x69, 0xd2, 0xb9, 0x6f, 0xde, 0xa1, 0x5f,
-    0xbe, 0x61, 0xc2, 0x99, 0x2f, 0x5e, 0xbc, 0x65, 0xca, 0x89, 0xf, 0x1e, 0x3c, 0x78, 0xf0, 0xfd,
-    0xe7, 0xd3, 0xbb, 0x6b, 0xd6, 0xb1, 0x7f, 0xfe, 0xe1, 0xdf, 0xa3, 0x5b, 0xb6, 0x71, 0xe2, 0xd9,
-    0xaf, 0x43, 0x86, 0x11, 0x22, 0x44, 0x88, 0xd, 0x1a, 0x34, 0x68, 0xd0, 0xbd, 0x67, 0xce, 0x81,
-    0x1f, 0x3e, 0x7c, 0xf8, 0xed, 0xc7, 0x93, 0x3b, 0x76, 0xec, 0xc5, 0x97, 0x33, 0x66, 0xcc, 0x85,
-    0x17, 0x2e, 0x5c, 0xb8, 0x6d, 0xda, 0xa9, 0x4f, 0x9e, 0x21, 0x42, 0x84, 0x15, 0x2a, 0x54, 0xa8,
-    0x4d, 0x9a, 0x29, 0x52, 0xa4, 0x55, 0xaa, 0x49, 0x92, 0x39, 0x72, 0xe4, 0xd5, 0xb7, 0x73, 0xe6,
-    0xd1, 0xbf, 0x63, 0xc6, 0x91, 0x3f, 0x7e, 0xfc, 0xe5, 0xd7, 0xb3, 0x7b, 0xf6, 0xf1, 0xff, 0xe3,
-    0xdb, 0xab, 0x4b, 0x96, 0x31, 0x62, 0xc4, 0x95, 0x37, 0x6e, 0xdc, 0xa5, 0x57, 0xae, 0x41, 0x82,
-    0x19, 0x32, 0x64, 0xc8, 0x8d, 0x7, 0xe, 0x1c, 0x38, 0x70, 0xe0, 0xdd, 0xa7, 0x53, 0xa6, 0x51,
-    0xa2, 0x59, 0xb2, 0x79, 0xf2, 0xf9, 0xef, 0xc3, 0x9b, 0x2b, 0x56, 0xac, 0x45, 0x8a, 0x9, 0x12,
-    0x24, 0x48, 0x90, 0x3d, 0x7a, 0xf4, 0xf5, 0xf7, 0xf3, 0xfb, 0xeb, 0xcb, 0x8b, 0xb, 0x16, 0x2c,
-    0x58, 0xb0, 0x7d, 0xfa, 0xe9, 0xcf, 0x83, 0x1b, 0x36, 0x6c, 0xd8, 0xad, 0x47, 0x8e, 0x1, 0x2,
-    0x4, 0x8, 0x10, 0x20, 0x40, 0x80, 0x1d, 0x3a, 0x74, 0xe8, 0xcd, 0x87, 0x13, 0x26, 0x4c, 0x98,
-    0x2d, 0x5a, 0xb4, 0x75, 0xea, 0xc9, 0x8f, 0x3, 0x6, 0xc, 0x18, 0x30, 0x60, 0xc0, 0x9d, 0x27,
-    0x4e, 0x9c, 0x25, 0x4a, 0x94, 0x35, 0x6a, 0xd4, 0xb5, 0x77, 0xee, 0xc1, 0x9f, 0x23, 0x46, 0x8c,
-    0x5, 0xa, 0x14, 0x28, 0x50, 0xa0, 0x5d, 0xba, 0x69, 0xd2, 0xb9, 0x6f, 0xde, 0xa1, 0x5f, 0xbe,
-    0x61, 0xc2, 0x99, 0x2f, 0x5e, 0xbc, 0x65, 0xca, 0x89, 0xf, 0x1e, 0x3c, 0x78, 0xf0, 0xfd, 0xe7,
-    0xd3, 0xbb, 0x6b, 0xd6, 0xb1, 0x7f, 0xfe, 0xe1, 0xdf, 0xa3, 0x5b, 0xb6, 0x71, 0xe2, 0xd9, 0xaf,
-    0x43, 0x86, 0x11, 0x22, 0x44, 0x88, 0xd, 0x1a, 0x34, 0x68, 0xd0, 0xbd, 0x67, 0xce, 0x81, 0x1f,
-    0x3e, 0x7c, 0xf8, 0xed, 0xc7, 0x93, 0x3b, 0x76, 0xec, 0xc5, 0x97, 0x33, 0x66, 0xcc, 0x85, 0x17,
-    0x2e, 0x5c, 0xb8, 0x6d, 0xda, 0xa9, 0x4f, 0x9e, 0x21, 0x42, 0x84, 0x15, 0x2a, 0x54, 0xa8, 0x4d,
-    0x9a, 0x29, 0x52, 0xa4, 0x55, 0xaa, 0x49, 0x92, 0x39, 0x72, 0xe4, 0xd5, 0xb7, 0x73, 0xe6, 0xd1,
-    0xbf, 0x63, 0xc6, 0x91, 0x3f, 0x7e, 0xfc, 0xe5, 0xd7, 0xb3, 0x7b, 0xf6, 0xf1, 0xff, 0xe3, 0xdb,
-    0xab, 0x4b, 0x96, 0x31, 0x62, 0xc4, 0x95, 0x37, 0x6e, 0xdc, 0xa5, 0x57, 0xae, 0x41, 0x82, 0x19,
-    0x32, 0x64, 0xc8, 0x8d, 0x7, 0xe, 0x1c, 0x38, 0x70, 0xe0, 0xdd, 0xa7, 0x53, 0xa6, 0x51, 0xa2,
-    0x59, 0xb2, 0x79, 0xf2, 0xf9, 0xef, 0xc3, 0x9b, 0x2b, 0x56, 0xac, 0x45, 0x8a, 0x9, 0x12, 0x24,
-    0x48, 0x90, 0x3d, 0x7a, 0xf4, 0xf5, 0xf7, 0xf3, 0xfb, 0xeb, 0xcb, 0x8b, 0xb, 0x16, 0x2c, 0x58,
-    0xb0, 0x7d, 0xfa, 0xe9, 0xcf, 0x83, 0x1b, 0x36, 0x6c, 0xd8, 0xad, 0x47, 0x8e, 0x1, 0x2
-];
-
-const LOG_SIZE: usize = 256; 
-pub static LOG: [u8; LOG_SIZE] = [
-    0x0, 0x0, 0x1, 0x19, 0x2, 0x32, 0x1a, 0xc6, 0x3, 0xdf, 0x33, 0xee, 0x1b, 0x68, 0xc7, 0x4b, 0x4,
-    0x64, 0xe0, 0xe, 0x34, 0x8d, 0xef, 0x81, 0x1c, 0xc1, 0x69, 0xf8, 0xc8, 0x8, 0x4c, 0x71, 0x5,
-    0x8a, 0x65, 0x2f, 0xe1, 0x24, 0xf, 0x21, 0x35, 0x93, 0x8e, 0xda, 0xf0, 0x12, 0x82, 0x45, 0x1d,
-    0xb5, 0xc2, 0x7d, 0x6a, 0x27, 0xf9, 0xb9, 0xc9, 0x9a, 0x9, 0x78, 0x4d, 0xe4, 0x72, 0xa6, 0x6,
-    0xbf, 0x8b, 0x62, 0x66, 0xdd, 0x30, 0xfd, 0xe2, 0x98, 0x25, 0xb3, 0x10, 0x91, 0x22, 0x88, 0x36,
-    0xd0, 0x94, 0xce, 0x8f, 0x96, 0xdb, 0xbd, 0xf1, 0xd2, 0x13, 0x5c, 0x83, 0x38, 0x46, 0x40, 0x1e,
-    0x42, 0xb6, 0xa3, 0xc3, 0x48, 0x7e, 0x6e, 0x6b, 0x3a, 0x28, 0x54, 0xfa, 0x85, 0xba, 0x3d, 0xca,
-    0x5e, 0x9b, 0x9f, 0xa, 0x15, 0x79, 0x2b, 0x4e, 0xd4, 0xe5, 0xac, 0x73, 0xf3, 0xa7, 0x57, 0x7,
-    0x70, 0xc0, 0xf7, 0x8c, 0x80, 0x63, 0xd, 0x67, 0x4a, 0xde, 0xed, 0x31, 0xc5, 0xfe, 0x18, 0xe3,
-    0xa5, 0x99, 0x77, 0x26, 0xb8, 0xb4, 0x7c, 0x11, 0x44, 0x92, 0xd9, 0x23, 0x20, 0x89, 0x2e, 0x37,
-    0x3f, 0xd1, 0x5b, 0x95, 0xbc, 0xcf, 0xcd, 0x90, 0x87, 0x97, 0xb2, 0xdc, 0xfc, 0xbe, 0x61, 0xf2,
-    0x56, 0xd3, 0xab, 0x14, 0x2a, 0x5d, 0x9e, 0x84, 0x3c, 0x39, 0x53, 0x47, 0x6d, 0x41, 0xa2, 0x1f,
-    0x2d, 0x43, 0xd8, 0xb7, 0x7b, 0xa4, 0x76, 0xc4, 0x17, 0x49, 0xec, 0x7f, 0xc, 0x6f, 0xf6, 0x6c,
-    0xa1, 0x3b, 0x52, 0x29, 0x9d, 0x55, 0xaa, 0xfb, 0x60, 0x86, 0xb1, 0xbb, 0xcc, 0x3e, 0x5a, 0xcb,
-    0x59, 0x5f, 0xb0, 0x9c, 0xa9, 0xa0, 0x51, 0xb, 0xf5, 0x16, 0xeb, 0x7a, 0x75, 0x2c, 0xd7, 0x4f,
-    0xae, 0xd5, 0xe9, 0xe6, 0xe7, 0xad, 0xe8, 0x74, 0xd6, 0xf4, 0xea, 0xa8, 0x50, 0x58, 0xaf
-];
-
-/// Primitive operations over Galua Fields
-
-#[allow(dead_code)]
+const LOG_SIZE: usize = 256;
+
+/// Default GF(2^8) field, its tables are generated at compile time
+const GF2_8: BinaryField = BinaryField::new(0x11d, 2);
+
+pub static EXP: [u8; EXP_SIZE] = GF2_8.exp;
+pub static LOG: [u8; LOG_SIZE] = GF2_8.log;
+
+// Primitive operations over Galua Fields
+
 #[inline]
 pub fn add(x: u8, y: u8) -> u8 {
     x ^ y
@@ -85,20 +59,31 @@ pub fn mul(x: u8, y: u8) -> u8 {
     }
 }
 
+/// Division, `None` if `y` is zero
 #[inline]
-pub fn div(x: u8, y: u8) -> u8 {
-    debug_assert!(y != 0);
-    if x == 0 {
-        0
+pub fn checked_div(x: u8, y: u8) -> Option<u8> {
+    if y == 0 {
+        None
+    } else if x == 0 {
+        Some(0)
     } else {
         let log_x = uncheck!(LOG[x as usize]) as usize;
         let log_y = uncheck!(LOG[y as usize]) as usize;
         let exp_index = (log_x + 255 - log_y) % 255;
 
-        uncheck!(EXP[exp_index])
+        Some(uncheck!(EXP[exp_index]))
     }
 }
 
+/// Division, zero if `y` is zero, same as `Gf2_8ConstantTime`.
+///
+/// Decoder divides by zero only if the message is corrupted beyond repair,
+/// then the result fails the final syndrome check whatever the quotient is.
+#[inline]
+pub fn div(x: u8, y: u8) -> u8 {
+    checked_div(x, y).unwrap_or(0)
+}
+
 #[inline]
 pub fn pow(x: u8, power: i32) -> u8 {
     let mut i = uncheck!(LOG[x as usize]) as i32
@@ -118,6 +103,35 @@ pub fn inverse(x: u8) -> u8 {
     uncheck!(EXP[exp_index as usize])
 }
 
+/// Base of the roots of a code with root spacing `prim`: `primitive^prim`
+///
+/// # Panics
+/// Panics if `prim` is zero or shares a factor with `order - 1`, then the base is not primitive
+/// and different error positions would have the same locator.
+pub fn root_base<F: Field>(field: &F, prim: usize) -> F::Elem {
+    // Orders of fields too wide for `usize` saturate to `usize::MAX`, which is `order - 1` of GF(2^64)
+    let group = match field.order() {
+        usize::MAX => usize::MAX,
+        order => order - 1,
+    };
+
+    let (mut a, mut b) = (prim, group);
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    assert!(prim != 0 && a == 1, "root spacing must be coprime with the order of the multiplicative group");
+
+    field.pow(field.primitive(), (prim % group) as i32)
+}
+
+/// `mul` for constant expressions, without tables
+#[inline]
+pub const fn mul_const(x: u8, y: u8) -> u8 {
+    gf2_8::mul_bitwise(x, y)
+}
+
 #[cfg(test)]
 mod tests {
     use super::EXP;
@@ -207,6 +221,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn div_by_zero() {
+        for x in 0..=255 {
+            assert_eq!(None, super::checked_div(x, 0));
+            assert_eq!(0, super::div(x, 0));
+        }
+        assert_eq!(Some(super::div(7, 3)), super::checked_div(7, 3));
+    }
+
     #[test]
     fn pow() {
         let answers: [u8; LOG_SIZE] = [
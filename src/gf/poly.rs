@@ -1,97 +1,467 @@
-#[derive(Copy)]
-pub struct Polynom {
-    array: [u8; ::POLYNOMIAL_MAX_LENGTH],
+use core::marker::PhantomData;
+use ::gf::poly_math::{self, Scale, Add, Mul, Div, Eval, EvalMany, Derivative, Compose};
+use ::gf::{Field, Symbol, Gf2_8, ArithmeticError};
+
+/// Backing storage of polynomial coefficients
+pub trait Storage<T>: Clone + AsRef<[T]> + AsMut<[T]> {
+    /// Maximum number of coefficients, `usize::MAX` if unbounded
+    const CAPACITY: usize;
+
+    /// Makes at least `len` coefficients available, new coefficients are zero.
+    ///
+    /// # Panics
+    /// Fixed-size storage doesn't grow and panics if `len` exceeds `CAPACITY`.
+    fn reserve(&mut self, len: usize);
+}
+
+impl<T: Symbol, const N: usize> Storage<T> for [T; N] {
+    const CAPACITY: usize = N;
+
+    #[inline]
+    fn reserve(&mut self, len: usize) {
+        assert!(len <= N, "polynomial of {} coefficients exceeds the capacity of {}", len, N);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Symbol> Storage<T> for ::alloc::vec::Vec<T> {
+    const CAPACITY: usize = usize::MAX;
+
+    #[inline]
+    fn reserve(&mut self, len: usize) {
+        if len > self.len() {
+            self.resize(len, T::ZERO);
+        }
+    }
+}
+
+/// Polynomial over a field, with coefficients stored in the field's `Array` storage: inline by default, `Vec` with `Heap`.
+///
+/// Coefficients are ordered from the highest degree to the lowest, like symbols of a codeword:
+/// `[1, 2, 3]` is `x^2 + 2x + 3`. Leading zero coefficients are allowed, `trim` removes them.
+/// The number of coefficients is bounded by `capacity`: operations that would produce longer
+/// polynomials panic.
+///
+/// Arithmetic takes the field as the last argument, so that fields built at runtime
+/// can be used without storing them in every polynomial.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::{Polynom, Gf2_8};
+///
+/// let p: Polynom = Polynom::from(&[1, 2, 3][..]);
+/// let q: Polynom = Polynom::from(&[1, 5][..]);
+///
+/// let (quotient, remainder) = p.mul(&q, &Gf2_8).add(&[7], &Gf2_8).div_rem(&q, &Gf2_8).unwrap();
+/// assert_eq!(&p[..], &quotient[..]);
+/// assert_eq!(&[7], &remainder[..]);
+///
+/// assert_eq!(Some(2), p.degree());
+/// // x + 5 has a root at 5
+/// assert_eq!(0, p.mul(&q, &Gf2_8).eval(5, &Gf2_8));
+/// ```
+pub struct Polynom<F: Field = Gf2_8> {
+    array: F::Array,
     length: usize,
     dirty: bool,
+    _field: PhantomData<F>,
 }
 
-impl Polynom {
+impl<F: Field> Polynom<F> {
+    /// Constructs an empty polynomial
     #[inline]
-    pub fn new() -> Polynom {
+    pub fn new() -> Polynom<F> {
         Polynom {
-            array: [0; ::POLYNOMIAL_MAX_LENGTH],
+            array: F::ZEROS,
             length: 0,
             dirty: false,
+            _field: PhantomData,
         }
     }
 
+    /// Constructs a zero polynomial with `len` coefficients
+    ///
+    /// # Panics
+    /// Panics if `len` exceeds `capacity()`.
     #[inline]
-    pub fn with_length(len: usize) -> Polynom {
-        let mut p = Polynom::new();
+    pub fn with_length(len: usize) -> Polynom<F> {
+        let mut p = Polynom::<F>::new();
+        p.array.reserve(len);
         p.length = len;
         p
     }
 
+    /// Truncates or extends the polynomial with zeros to `new_len` coefficients.
+    /// Extending shifts existing coefficients to higher degrees.
+    ///
+    /// # Panics
+    /// Panics if `new_len` exceeds `capacity()`.
     #[inline]
     pub fn set_length(&mut self, new_len: usize) {
         let old_len = self.len();
+        self.array.reserve(new_len);
         self.length = new_len;
         
         if self.dirty && new_len > old_len {
             for x in self.iter_mut().skip(old_len)
                                     .take(new_len - old_len) 
             {
-                *x = 0;
+                *x = F::Elem::ZERO;
             }
         } else if new_len < old_len {
             self.dirty = true;
         }
     }
 
+    /// Number of coefficients, including leading zeros
     #[inline]
     pub fn len(&self) -> usize {
         self.length
     }
 
+    /// Checks that the polynomial has no coefficients
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Maximum length of polynomials over the field
+    #[inline]
+    pub fn capacity() -> usize {
+        F::Array::CAPACITY
+    }
+
+    /// Reverses order of coefficients
     #[inline]
     pub fn reverse(mut self) -> Self {
         (*self).reverse();
         self
     }
 
+    /// Appends a coefficient of the lowest degree, multiplying the polynomial by `x` first
+    ///
+    /// # Panics
+    /// Panics if the polynomial is already `capacity()` coefficients long.
     #[inline]
-    pub fn push(&mut self, x: u8) {
-        self.array[self.length] = x;
+    pub fn push(&mut self, x: F::Elem) {
+        self.array.reserve(self.length + 1);
+        self.array.as_mut()[self.length] = x;
         self.length += 1;
     }
+
+    /// Number of leading zero coefficients
+    #[inline]
+    fn leading_zeros(&self) -> usize {
+        self.iter().take_while(|&&x| x == F::Elem::ZERO).count()
+    }
+
+    /// Checks that every coefficient is zero
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.leading_zeros() == self.len()
+    }
+
+    /// Degree of the polynomial ignoring leading zeros, `None` for the zero polynomial
+    #[inline]
+    pub fn degree(&self) -> Option<usize> {
+        let len = self.len() - self.leading_zeros();
+        if len == 0 { None } else { Some(len - 1) }
+    }
+
+    /// Highest non-zero coefficient, `None` for the zero polynomial
+    #[inline]
+    pub fn leading_coefficient(&self) -> Option<F::Elem> {
+        self.iter().cloned().find(|&x| x != F::Elem::ZERO)
+    }
+
+    /// Removes leading zero coefficients, the zero polynomial becomes empty
+    #[inline]
+    pub fn trim(&mut self) {
+        let zeros = self.leading_zeros();
+        if zeros != 0 {
+            let len = self.len();
+            self.copy_within(zeros..len, 0);
+            self.set_length(len - zeros);
+        }
+    }
+
+    /// Sum of polynomials, as long as the longer one
+    ///
+    /// # Panics
+    /// Panics if `rhs` is longer than `capacity()`.
+    #[inline]
+    pub fn add(&self, rhs: &[F::Elem], field: &F) -> Polynom<F> {
+        Add::add(&self[..], rhs, field)
+    }
+
+    /// Difference of polynomials, as long as the longer one
+    ///
+    /// # Panics
+    /// Panics if `rhs` is longer than `capacity()`.
+    #[inline]
+    pub fn sub(&self, rhs: &[F::Elem], field: &F) -> Polynom<F> {
+        let mut neg = Polynom::<F>::from(rhs);
+        for x in neg.iter_mut() {
+            *x = field.neg(*x);
+        }
+        Add::add(&self[..], &neg, field)
+    }
+
+    /// Product of polynomials, `self.len() + rhs.len() - 1` coefficients long
+    ///
+    /// # Panics
+    /// Panics if the product is longer than `capacity()`, e.g. for two polynomials
+    /// of 200 coefficients over `Gf2_8`.
+    #[inline]
+    pub fn mul(&self, rhs: &[F::Elem], field: &F) -> Polynom<F> {
+        if self.is_empty() || rhs.is_empty() {
+            return Polynom::new();
+        }
+        Mul::mul(&self[..], rhs, field)
+    }
+
+    /// Product of the polynomial and a scalar
+    #[inline]
+    pub fn scale(&self, x: F::Elem, field: &F) -> Polynom<F> {
+        Scale::scale(&self[..], x, field)
+    }
+
+    /// Value of the polynomial at `x`
+    #[inline]
+    pub fn eval(&self, x: F::Elem, field: &F) -> F::Elem {
+        if self.is_empty() {
+            return F::Elem::ZERO;
+        }
+        Eval::eval(&self[..], x, field)
+    }
+
+    /// Values of the polynomial at every point of `xs`, written to `ys`
+    ///
+    /// # Panics
+    /// Panics if `xs` and `ys` have different lengths.
+    #[inline]
+    pub fn eval_many(&self, xs: &[F::Elem], ys: &mut [F::Elem], field: &F) {
+        if self.is_empty() {
+            assert_eq!(xs.len(), ys.len());
+            ys.fill(F::Elem::ZERO);
+            return;
+        }
+        EvalMany::eval_many(&self[..], xs, ys, field)
+    }
+
+    /// Lagrange interpolation: the polynomial of degree less than `xs.len()` with value `ys[i]`
+    /// at `xs[i]`, `xs.len()` coefficients long.
+    ///
+    /// Fails with `ArithmeticError::DivisionByZero` if `xs` are not distinct.
+    ///
+    /// # Panics
+    /// Panics if `xs` and `ys` have different lengths.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::{Polynom, Gf2_8};
+    ///
+    /// // Shamir's secret sharing: shares are values at 1..=n, the secret is the value at zero
+    /// let secret: Polynom = Polynom::from(&[0x17, 0x99, 0x42][..]);
+    /// let mut shares = [0; 3];
+    /// secret.eval_many(&[1, 2, 3], &mut shares, &Gf2_8);
+    ///
+    /// let poly = Polynom::interpolate_lagrange(&[1, 2, 3], &shares, &Gf2_8).unwrap();
+    /// assert_eq!(0x42, poly.eval(0, &Gf2_8));
+    /// ```
+    #[inline]
+    pub fn interpolate_lagrange(xs: &[F::Elem], ys: &[F::Elem], field: &F)
+                                -> Result<Polynom<F>, ArithmeticError> {
+        poly_math::lagrange(xs, ys, field)
+    }
+
+    /// Same as `interpolate_lagrange`, with Newton's divided differences
+    #[inline]
+    pub fn interpolate_newton(xs: &[F::Elem], ys: &[F::Elem], field: &F)
+                              -> Result<Polynom<F>, ArithmeticError> {
+        poly_math::newton(xs, ys, field)
+    }
+
+    /// Quotient and remainder of the division by `rhs`, both trimmed.
+    /// Divisor doesn't have to be monic.
+    ///
+    /// Fails with `ArithmeticError::DivisionByZero` if `rhs` is the zero polynomial.
+    pub fn div_rem(&self, rhs: &[F::Elem], field: &F)
+                   -> Result<(Polynom<F>, Polynom<F>), ArithmeticError> {
+        let mut divisor = Polynom::<F>::from(rhs);
+        divisor.trim();
+        let lead = match divisor.leading_coefficient() {
+            Some(lead) => lead,
+            None => return Err(ArithmeticError::DivisionByZero),
+        };
+
+        let mut dividend = self.clone();
+        dividend.trim();
+        if dividend.len() < divisor.len() {
+            return Ok((Polynom::new(), dividend));
+        }
+
+        // Division by a monic divisor, quotient is scaled back by its leading coefficient
+        let lead_inv = field.inverse(lead);
+        divisor.scale_assign(lead_inv, field);
+
+        let (mut quotient, mut remainder) = Div::div(&dividend[..], &divisor, field);
+        quotient.scale_assign(lead_inv, field);
+        quotient.trim();
+        remainder.trim();
+
+        Ok((quotient, remainder))
+    }
+
+    /// Formal derivative
+    #[inline]
+    pub fn derivative(&self, field: &F) -> Polynom<F> {
+        Derivative::derivative(&self[..], field)
+    }
+
+    /// Polynomial scaled to have leading coefficient one, and trimmed.
+    /// The zero polynomial stays zero.
+    pub fn monic(&self, field: &F) -> Polynom<F> {
+        let mut poly = self.clone();
+        poly.trim();
+        if let Some(lead) = poly.leading_coefficient() {
+            poly.scale_assign(field.inverse(lead), field);
+        }
+        poly
+    }
+
+    /// Composition `self(rhs(x))`
+    #[inline]
+    pub fn compose(&self, rhs: &[F::Elem], field: &F) -> Polynom<F> {
+        Compose::compose(&self[..], rhs, field)
+    }
+
+    /// Monic greatest common divisor, zero if both polynomials are zero
+    #[inline]
+    pub fn gcd(&self, rhs: &[F::Elem], field: &F) -> Polynom<F> {
+        self.extended_gcd(rhs, field).0
+    }
+
+    /// Extended Euclidean algorithm: monic `gcd` and Bézout coefficients `s`, `t`,
+    /// such that `s * self + t * rhs == gcd`.
+    pub fn extended_gcd(&self, rhs: &[F::Elem], field: &F) -> (Polynom<F>, Polynom<F>, Polynom<F>) {
+        let (mut r, mut s, mut t) = self.euclid(rhs, None, field);
+        if let Some(lead) = r.leading_coefficient() {
+            let lead_inv = field.inverse(lead);
+            r.scale_assign(lead_inv, field);
+            s.scale_assign(lead_inv, field);
+            t.scale_assign(lead_inv, field);
+        }
+        (r, s, t)
+    }
+
+    /// Extended Euclidean algorithm stopped at the first remainder `r` of degree
+    /// less than `stop_degree`, returned along with `s`, `t`, such that `s * self + t * rhs == r`.
+    ///
+    /// This is the key equation solver of Sugiyama decoding: for `self = x^2t` and syndromes
+    /// as `rhs`, stopping at degree `t` gives the error evaluator as `r`
+    /// and the error locator as `t`, up to a scalar.
+    #[inline]
+    pub fn extended_euclid(&self, rhs: &[F::Elem], stop_degree: usize, field: &F)
+                           -> (Polynom<F>, Polynom<F>, Polynom<F>) {
+        self.euclid(rhs, Some(stop_degree), field)
+    }
+
+    /// Runs Euclid's algorithm until a remainder of degree less than `stop_degree`,
+    /// or until the last non-zero remainder if there is no stop degree
+    fn euclid(&self, rhs: &[F::Elem], stop_degree: Option<usize>, field: &F)
+              -> (Polynom<F>, Polynom<F>, Polynom<F>) {
+        let below_stop = |poly: &Polynom<F>| match (stop_degree, poly.degree()) {
+            (Some(stop), Some(degree)) => degree < stop,
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+
+        let mut prev = (self.clone(), Polynom::<F>::from(&[F::Elem::ONE][..]), Polynom::<F>::new());
+        let mut cur = (Polynom::<F>::from(rhs), Polynom::<F>::new(), Polynom::<F>::from(&[F::Elem::ONE][..]));
+        prev.0.trim();
+        cur.0.trim();
+
+        if below_stop(&prev.0) {
+            return prev;
+        }
+
+        loop {
+            if below_stop(&cur.0) {
+                return cur;
+            }
+            if cur.0.is_zero() {
+                return prev;
+            }
+
+            let (quotient, remainder) = match prev.0.div_rem(&cur.0, field) {
+                Ok(division) => division,
+                Err(_) => unreachable!(),
+            };
+
+            let mut s = prev.1.sub(&quotient.mul(&cur.1, field), field);
+            let mut t = prev.2.sub(&quotient.mul(&cur.2, field), field);
+            s.trim();
+            t.trim();
+
+            prev = cur;
+            cur = (remainder, s, t);
+        }
+    }
 }
 
-impl Clone for Polynom {
+impl<F: Field> Copy for Polynom<F> where F::Array: Copy {}
+
+impl<F: Field> Clone for Polynom<F> {
     #[inline]
-    fn clone(&self) -> Polynom {
-        *self
+    fn clone(&self) -> Polynom<F> {
+        Polynom {
+            array: self.array.clone(),
+            length: self.length,
+            dirty: self.dirty,
+            _field: PhantomData,
+        }
     }
 }
 
-impl Default for Polynom {
+impl<F: Field> Default for Polynom<F> {
     fn default() -> Self {
         Self::new()
     }
 }
 
 use core::ops::Deref;
-impl Deref for Polynom {
-    type Target = [u8];
+impl<F: Field> Deref for Polynom<F> {
+    type Target = [F::Elem];
     #[inline]
     fn deref(&self) -> &Self::Target {
         let len = self.len();
-        &self.array[0..len]
+        &self.array.as_ref()[0..len]
     }
 }
 
 use core::ops::DerefMut;
-impl DerefMut for Polynom {
+impl<F: Field> DerefMut for Polynom<F> {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
         let len = self.len();
-        &mut self.array[0..len]
+        &mut self.array.as_mut()[0..len]
     }
 }
 
-impl<'a> From<&'a [u8]> for Polynom {
+impl<F: Field> PartialEq for Polynom<F> {
     #[inline]
-    fn from(slice: &'a [u8]) -> Polynom {
-        debug_assert!(slice.len() <= ::POLYNOMIAL_MAX_LENGTH);
+    fn eq(&self, other: &Polynom<F>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<F: Field> Eq for Polynom<F> {}
+
+/// Copies coefficients of a slice, panicking if it is longer than `capacity()`
+impl<'a, F: Field> From<&'a [F::Elem]> for Polynom<F> {
+    #[inline]
+    fn from(slice: &'a [F::Elem]) -> Polynom<F> {
         let mut poly = Polynom::with_length(slice.len());
         poly[..].copy_from_slice(slice);
         poly
@@ -99,7 +469,7 @@ impl<'a> From<&'a [u8]> for Polynom {
 }
 
 use core::fmt;
-impl fmt::Debug for Polynom {
+impl<F: Field> fmt::Debug for Polynom<F> {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         write!(fmt, "{:?}", &self[..])
     }
@@ -107,9 +477,12 @@ impl fmt::Debug for Polynom {
 
 #[cfg(test)]
 mod tests {
+    use ::gf::{Field, Gf2_8, PrimeField, ArithmeticError};
+    use super::Polynom;
+
     #[test]
     fn push() {
-        let mut poly = polynom![];
+        let mut poly: Polynom<Gf2_8> = polynom![];
         for i in 0..10 {
             poly.push(i);
             for j in 0..(i as usize) {
@@ -120,7 +493,7 @@ mod tests {
 
     #[test]
     fn reverse() {
-        let poly = polynom![5, 4, 3, 2, 1, 0];
+        let poly: Polynom<Gf2_8> = polynom![5, 4, 3, 2, 1, 0];
         for (i, x) in poly.reverse().iter().enumerate() {
             assert_eq!(i, *x as usize);
         }
@@ -128,7 +501,7 @@ mod tests {
 
     #[test]
     fn set_length() {
-        let mut poly = polynom![1; 8];
+        let mut poly: Polynom<Gf2_8> = polynom![1; 8];
         poly.set_length(2);
         poly.set_length(6);
 
@@ -140,4 +513,147 @@ mod tests {
             assert_eq!(poly.array[i], 0);
         }
     }
+
+    #[test]
+    fn degree() {
+        let mut poly: Polynom<Gf2_8> = polynom![0, 0, 3, 0, 1];
+        assert_eq!(Some(2), poly.degree());
+        assert_eq!(Some(3), poly.leading_coefficient());
+        assert!(!poly.is_zero());
+
+        poly.trim();
+        assert_eq!([3, 0, 1], *poly);
+
+        let mut zero: Polynom<Gf2_8> = polynom![0, 0];
+        assert_eq!(None, zero.degree());
+        assert_eq!(None, zero.leading_coefficient());
+        assert!(zero.is_zero());
+
+        zero.trim();
+        assert!(zero.is_empty());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let field = &Gf2_8;
+        let p: Polynom<Gf2_8> = polynom![3, 9, 17, 24, 75];
+        let q: Polynom<Gf2_8> = polynom![0, 5, 10];
+
+        assert_eq!([3, 9, 17, 29, 65], *p.add(&q, field));
+        assert_eq!(*p.add(&q, field), *p.sub(&q, field));
+        assert_eq!([0, 15, 51, 15, 210, 138, 244], *p.mul(&q, field));
+        for (x, y) in p.scale(3, field).iter().zip(p.iter()) {
+            assert_eq!(*x, field.mul(*y, 3));
+        }
+        assert!(Polynom::<Gf2_8>::new().mul(&p, field).is_empty());
+        assert_eq!(0, Polynom::<Gf2_8>::new().eval(7, field));
+
+        // Non-monic divisor with a leading zero
+        let (quotient, remainder) = p.div_rem(&q, field).unwrap();
+        assert_eq!(Some(3), quotient.degree());
+        assert!(remainder.degree().unwrap() < 1);
+        let mut product = quotient.mul(&q, field).add(&remainder, field);
+        product.trim();
+        assert_eq!(p, product);
+
+        let (quotient, remainder) = q.div_rem(&p, field).unwrap();
+        assert!(quotient.is_empty());
+        assert_eq!([5, 10], *remainder);
+
+        assert_eq!(Err(::gf::ArithmeticError::DivisionByZero), p.div_rem(&[0, 0], field));
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the capacity")]
+    fn mul_overflow() {
+        let p: Polynom<Gf2_8> = polynom![1; 200];
+        // 399 coefficients, more than the 256 of `Gf2_8`
+        p.mul(&p, &Gf2_8);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the capacity")]
+    fn push_overflow() {
+        let mut p: Polynom<Gf2_8> = polynom![1; 256];
+        p.push(1);
+    }
+
+    #[test]
+    fn monic() {
+        let field = &Gf2_8;
+        let p: Polynom<Gf2_8> = polynom![0, 4, 8, 12];
+        assert_eq!([1, 2, 3], *p.monic(field));
+        assert!(Polynom::<Gf2_8>::with_length(3).monic(field).is_empty());
+    }
+
+    #[test]
+    fn gcd() {
+        let field = &Gf2_8;
+        let common: Polynom<Gf2_8> = polynom![1, 7, 9];
+        let a = common.mul(&[3, 1, 4], field);
+        let b = common.mul(&[5, 0, 2, 6], field);
+
+        assert_eq!(common, a.gcd(&b, field));
+        assert_eq!(common, b.gcd(&a, field));
+        assert_eq!(common, common.scale(9, field).gcd(&[], field));
+
+        let (g, s, t) = a.extended_gcd(&b, field);
+        let mut bezout = s.mul(&a, field).add(&t.mul(&b, field), field);
+        bezout.trim();
+        assert_eq!(g, bezout);
+    }
+
+    #[test]
+    fn extended_euclid() {
+        let field = &Gf2_8;
+        let a: Polynom<Gf2_8> = polynom![1, 0, 0, 0, 0, 0, 0];
+        let b: Polynom<Gf2_8> = polynom![12, 200, 7, 1, 9, 33];
+
+        for stop in 0..7 {
+            let (r, s, t) = a.extended_euclid(&b, stop, field);
+            assert!(r.degree().map_or(true, |degree| degree < stop));
+
+            let mut combination = s.mul(&a, field).add(&t.mul(&b, field), field);
+            combination.trim();
+            assert_eq!(r, combination);
+        }
+    }
+
+    #[test]
+    fn interpolate() {
+        let field = &Gf2_8;
+        let p: Polynom<Gf2_8> = polynom![12, 200, 7, 1, 9, 33];
+        let xs = [0, 1, 2, 3, 100, 255];
+        let mut ys = [0; 6];
+        p.eval_many(&xs, &mut ys, field);
+        for (x, y) in xs.iter().zip(ys.iter()) {
+            assert_eq!(p.eval(*x, field), *y);
+        }
+
+        assert_eq!(p, Polynom::interpolate_lagrange(&xs, &ys, field).unwrap());
+        assert_eq!(p, Polynom::interpolate_newton(&xs, &ys, field).unwrap());
+
+        // Extra points of a lower degree polynomial give leading zeros
+        let line: Polynom<Gf2_8> = polynom![0, 0, 0, 0, 5, 7];
+        line.eval_many(&xs, &mut ys, field);
+        assert_eq!(line, Polynom::interpolate_lagrange(&xs, &ys, field).unwrap());
+        assert_eq!(line, Polynom::interpolate_newton(&xs, &ys, field).unwrap());
+
+        let duplicate = [1, 2, 1];
+        assert_eq!(Err(ArithmeticError::DivisionByZero), Polynom::interpolate_lagrange(&duplicate, &[1, 2, 3], field));
+        assert_eq!(Err(ArithmeticError::DivisionByZero), Polynom::interpolate_newton(&duplicate, &[1, 2, 3], field));
+        assert!(Polynom::interpolate_newton(&[], &[], field).unwrap().is_empty());
+    }
+
+    #[test]
+    fn interpolate_prime() {
+        let field = &PrimeField::new(929, 3);
+        let p: Polynom<PrimeField> = polynom![900, 0, 17, 928, 3];
+        let xs = [1, 5, 9, 500, 928];
+        let mut ys = [0; 5];
+        p.eval_many(&xs, &mut ys, field);
+
+        assert_eq!(p, Polynom::interpolate_lagrange(&xs, &ys, field).unwrap());
+        assert_eq!(p, Polynom::interpolate_newton(&xs, &ys, field).unwrap());
+    }
 }
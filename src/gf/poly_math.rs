@@ -1,48 +1,61 @@
 use core::cmp::max;
 use ::gf::poly::Polynom;
-use ::gf;
+use ::gf::{Field, Symbol, ArithmeticError};
 
-pub trait Scale {
-    fn scale(&self, x: u8) -> Polynom;
-    fn scale_assign(&mut self, x: u8) -> &mut Self;
+pub trait Scale<F: Field> {
+    fn scale(&self, x: F::Elem, field: &F) -> Polynom<F>;
+    fn scale_assign(&mut self, x: F::Elem, field: &F) -> &mut Self;
 }
 
-pub trait Add {
-    fn add(&self, rhs: &Self) -> Polynom;
-    fn add_assign(&mut self, rhs: &Self) -> &mut Self;
+pub trait Add<F: Field> {
+    fn add(&self, rhs: &Self, field: &F) -> Polynom<F>;
+    #[allow(dead_code)]
+    fn add_assign(&mut self, rhs: &Self, field: &F) -> &mut Self;
 }
 
-pub trait Mul {
-    fn mul(&self, rhs: &Self) -> Polynom;
+pub trait Mul<F: Field> {
+    fn mul(&self, rhs: &Self, field: &F) -> Polynom<F>;
 }
 
-pub trait Div {
-    fn div(&self, rhs: &Self) -> (Polynom, Polynom);
+pub trait Div<F: Field> {
+    fn div(&self, rhs: &Self, field: &F) -> (Polynom<F>, Polynom<F>);
 }
 
-pub trait Eval {
-    fn eval(&self, x: u8) -> u8;
+pub trait Eval<F: Field> {
+    fn eval(&self, x: F::Elem, field: &F) -> F::Elem;
 }
 
-impl Scale for [u8] {
+pub trait EvalMany<F: Field> {
+    fn eval_many(&self, xs: &[F::Elem], ys: &mut [F::Elem], field: &F);
+}
+
+pub trait Derivative<F: Field> {
+    fn derivative(&self, field: &F) -> Polynom<F>;
+}
+
+pub trait Compose<F: Field> {
+    fn compose(&self, rhs: &Self, field: &F) -> Polynom<F>;
+}
+
+impl<F: Field> Scale<F> for [F::Elem] {
     #[inline]
-    fn scale(&self, x: u8) -> Polynom {
+    fn scale(&self, x: F::Elem, field: &F) -> Polynom<F> {
         let mut poly = Polynom::from(self);
-        poly.scale_assign(x);
+        poly.scale_assign(x, field);
         poly
     }
 
     #[inline]
-    fn scale_assign(&mut self, x: u8) -> &mut Self {
+    fn scale_assign(&mut self, x: F::Elem, field: &F) -> &mut Self {
         for px in self.iter_mut() {
-            *px = gf::mul(*px, x);
+            *px = field.mul(*px, x);
         }
         self
     }
 }
 
-impl Add for [u8] {
-    fn add(&self, rhs: &Self) -> Polynom {
+impl<F: Field> Add<F> for [F::Elem] {
+    fn add(&self, rhs: &Self, field: &F) -> Polynom<F> {
         let mut poly = Polynom::with_length(max(self.len(), rhs.len()));
 
         for (i, x) in self.iter().enumerate() {
@@ -52,27 +65,28 @@ impl Add for [u8] {
 
         for (i, x) in rhs.iter().enumerate() {
             let index = i + poly.len() - rhs.len();
-            uncheck_mut!(poly[index]) ^= *x;
+            uncheck_mut!(poly[index]) = field.add(uncheck!(poly[index]), *x);
         }
 
         poly
     }
 
-    fn add_assign(&mut self, rhs: &Self) -> &mut Self {
-        let poly = self.add(rhs);
+    fn add_assign(&mut self, rhs: &Self, field: &F) -> &mut Self {
+        let poly = self.add(rhs, field);
         self.copy_from_slice(&poly);
         self
     }
 }
 
-impl Mul for [u8] {
+impl<F: Field> Mul<F> for [F::Elem] {
     #[inline]
-    fn mul(&self, rhs: &Self) -> Polynom {
+    fn mul(&self, rhs: &Self, field: &F) -> Polynom<F> {
         let mut poly = Polynom::with_length(self.len() + rhs.len() - 1);
 
         for (j, rhs_x) in rhs.iter().enumerate() {
             for (i, self_x) in self.iter().enumerate() {
-                uncheck_mut!(poly[i + j]) ^= gf::mul(*self_x, *rhs_x);
+                let prod = field.mul(*self_x, *rhs_x);
+                uncheck_mut!(poly[i + j]) = field.add(uncheck!(poly[i + j]), prod);
             }
         }
 
@@ -80,8 +94,8 @@ impl Mul for [u8] {
     }
 }
 
-impl Div for [u8] {
-    fn div(&self, rhs: &Self) -> (Polynom, Polynom) {
+impl<F: Field> Div<F> for [F::Elem] {
+    fn div(&self, rhs: &Self, field: &F) -> (Polynom<F>, Polynom<F>) {
         let mut poly = Polynom::from(self);
 
         // If divisor's degree (len-1) is bigger, all dividend is a remainder
@@ -92,10 +106,11 @@ impl Div for [u8] {
 
         for i in 0..(self.len() - divisor_degree) {
             let coef = uncheck!(poly[i]);
-            if coef != 0 {
+            if coef != F::Elem::ZERO {
                 for j in 1..rhs.len() {
-                    if rhs[j] != 0 {
-                        uncheck_mut!(poly[i + j]) ^= gf::mul(rhs[j], coef);
+                    if rhs[j] != F::Elem::ZERO {
+                        let prod = field.mul(rhs[j], coef);
+                        uncheck_mut!(poly[i + j]) = field.sub(uncheck!(poly[i + j]), prod);
                     }
                 }
             }
@@ -113,27 +128,164 @@ impl Div for [u8] {
     }
 }
 
-impl Eval for [u8] {
+impl<F: Field> Eval<F> for [F::Elem] {
     #[inline]
-    fn eval(&self, x: u8) -> u8 {
+    fn eval(&self, x: F::Elem, field: &F) -> F::Elem {
         let mut y = self[0];
         for px in self.iter().skip(1) {
-            y = gf::mul(y, x) ^ px;
+            y = field.add(field.mul(y, x), *px);
         }
         y
     }
 }
 
+impl<F: Field> EvalMany<F> for [F::Elem] {
+    fn eval_many(&self, xs: &[F::Elem], ys: &mut [F::Elem], field: &F) {
+        assert_eq!(xs.len(), ys.len());
+        for (x, y) in xs.iter().zip(ys.iter_mut()) {
+            *y = self.eval(*x, field);
+        }
+    }
+}
+
+/// Polynomial of degree less than `xs.len()` passing through points `(xs[i], ys[i])`,
+/// built from the Lagrange basis.
+///
+/// Fails with `ArithmeticError::DivisionByZero` if points are not distinct.
+pub fn lagrange<F: Field>(xs: &[F::Elem], ys: &[F::Elem], field: &F)
+                          -> Result<Polynom<F>, ArithmeticError> {
+    assert_eq!(xs.len(), ys.len());
+    let n = xs.len();
+    let mut poly = Polynom::with_length(n);
+    if n == 0 {
+        return Ok(poly);
+    }
+
+    // Product of (x - xs[j]) over all points
+    let mut roots = Polynom::<F>::from(&[F::Elem::ONE][..]);
+    for x in xs.iter() {
+        roots.push(F::Elem::ZERO);
+        for i in (1..roots.len()).rev() {
+            let prod = field.mul(uncheck!(roots[i - 1]), *x);
+            uncheck_mut!(roots[i]) = field.sub(uncheck!(roots[i]), prod);
+        }
+    }
+
+    let mut basis = Polynom::<F>::with_length(n);
+    for (xi, yi) in xs.iter().zip(ys.iter()) {
+        // Product of (x - xs[j]) for j != i, by synthetic division of the full product
+        let mut acc = F::Elem::ZERO;
+        for k in 0..n {
+            acc = field.add(field.mul(acc, *xi), uncheck!(roots[k]));
+            uncheck_mut!(basis[k]) = acc;
+        }
+
+        let denominator = basis.eval(*xi, field);
+        if denominator == F::Elem::ZERO {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        let coef = field.div(*yi, denominator);
+        for k in 0..n {
+            let term = field.mul(uncheck!(basis[k]), coef);
+            uncheck_mut!(poly[k]) = field.add(uncheck!(poly[k]), term);
+        }
+    }
+
+    Ok(poly)
+}
+
+/// Polynomial of degree less than `xs.len()` passing through points `(xs[i], ys[i])`,
+/// built from Newton's divided differences.
+///
+/// Fails with `ArithmeticError::DivisionByZero` if points are not distinct.
+pub fn newton<F: Field>(xs: &[F::Elem], ys: &[F::Elem], field: &F)
+                        -> Result<Polynom<F>, ArithmeticError> {
+    assert_eq!(xs.len(), ys.len());
+    let n = xs.len();
+    if n == 0 {
+        return Ok(Polynom::new());
+    }
+
+    // Divided differences f[x0..xi], computed in place
+    let mut diffs = Polynom::<F>::from(ys);
+    for j in 1..n {
+        for i in (j..n).rev() {
+            let denominator = field.sub(xs[i], xs[i - j]);
+            if denominator == F::Elem::ZERO {
+                return Err(ArithmeticError::DivisionByZero);
+            }
+            let numerator = field.sub(uncheck!(diffs[i]), uncheck!(diffs[i - 1]));
+            uncheck_mut!(diffs[i]) = field.div(numerator, denominator);
+        }
+    }
+
+    // Horner's method on the Newton form, multiplying by (x - xs[i]) at each step
+    let mut poly = Polynom::<F>::with_length(n);
+    let last = n - 1;
+    uncheck_mut!(poly[last]) = uncheck!(diffs[last]);
+    for i in (0..last).rev() {
+        for k in i..last {
+            let prod = field.mul(uncheck!(poly[k]), xs[i]);
+            uncheck_mut!(poly[k]) = field.sub(uncheck!(poly[k + 1]), prod);
+        }
+        let prod = field.mul(uncheck!(poly[last]), xs[i]);
+        uncheck_mut!(poly[last]) = field.sub(uncheck!(diffs[i]), prod);
+    }
+
+    Ok(poly)
+}
+
+impl<F: Field> Derivative<F> for [F::Elem] {
+    fn derivative(&self, field: &F) -> Polynom<F> {
+        let degree = self.len().saturating_sub(1);
+        let mut poly = Polynom::with_length(degree);
+
+        // Coefficient of x^k is (k + 1) times the coefficient of x^(k + 1)
+        for (i, x) in self.iter().take(degree).enumerate() {
+            uncheck_mut!(poly[i]) = times(*x, degree - i, field);
+        }
+
+        poly
+    }
+}
+
+impl<F: Field> Compose<F> for [F::Elem] {
+    fn compose(&self, rhs: &Self, field: &F) -> Polynom<F> {
+        // Horner's method with polynomial argument
+        let mut poly = Polynom::<F>::new();
+        for x in self.iter() {
+            poly = poly.mul(rhs, field).add(&[*x], field);
+        }
+        poly
+    }
+}
+
+/// `x` added to itself `n` times
+#[inline]
+fn times<F: Field>(x: F::Elem, mut n: usize, field: &F) -> F::Elem {
+    let mut acc = F::Elem::ZERO;
+    let mut x = x;
+    while n != 0 {
+        if n & 1 != 0 {
+            acc = field.add(acc, x);
+        }
+        x = field.add(x, x);
+        n >>= 1;
+    }
+    acc
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ::gf::Gf2_8;
 
     #[test]
     fn scale() {
         let poly = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
         let answer = [0, 3, 6, 5, 12, 15, 10, 9, 24, 27];
-        assert_eq!(answer, *(poly.scale(3)));
+        assert_eq!(answer, *(poly.scale(3, &Gf2_8)));
     }
 
     #[test]
@@ -142,7 +294,7 @@ mod tests {
         let answer = [0, 3, 6, 5, 12, 15, 10, 9, 24, 27];
         assert_eq!(answer,
                    *({
-                       poly.scale_assign(3);
+                       poly.scale_assign(3, &Gf2_8);
                        &poly
                    }));
     }
@@ -151,26 +303,26 @@ mod tests {
     fn add() {
         let px = [0, 5, 10, 15, 20];
         let py = [3, 9, 17, 24, 75];
-        assert_eq!([3, 12, 27, 23, 95], *(px.add(&py)));
+        assert_eq!([3, 12, 27, 23, 95], *(px.add(&py, &Gf2_8)));
 
         let px = [0, 5, 10];
         let py = [3, 9, 17, 24, 75];
 
-        assert_eq!([3, 9, 17, 29, 65], *(px.add(&py)));
-        assert_eq!([3, 9, 17, 29, 65], *(py.add(&px)))
+        assert_eq!([3, 9, 17, 29, 65], *(px.add(&py, &Gf2_8)));
+        assert_eq!([3, 9, 17, 29, 65], *(py.add(&px, &Gf2_8)))
     }
 
     #[test]
     fn mul() {
         let px = [0, 5, 10, 15, 20];
         let py = [3, 9, 17, 24, 75];
-        assert_eq!([0, 15, 51, 30, 153, 193, 53, 115, 245], *(px.mul(&py)));
+        assert_eq!([0, 15, 51, 30, 153, 193, 53, 115, 245], *(px.mul(&py, &Gf2_8)));
 
         let px = [0, 5, 10];
         let py = [3, 9, 17, 24, 75];
 
-        assert_eq!([0, 15, 51, 15, 210, 138, 244], *(px.mul(&py)));
-        assert_eq!([0, 15, 51, 15, 210, 138, 244], *(py.mul(&px)));
+        assert_eq!([0, 15, 51, 15, 210, 138, 244], *(px.mul(&py, &Gf2_8)));
+        assert_eq!([0, 15, 51, 15, 210, 138, 244], *(py.mul(&px, &Gf2_8)));
     }
 
     #[test]
@@ -178,11 +330,11 @@ mod tests {
         let px = [0, 5, 10, 15, 20];
         let py = [3, 9, 17, 24, 75];
 
-        let (q, r) = px.div(&py);
+        let (q, r) = px.div(&py, &Gf2_8);
         assert_eq!([0], *q);
         assert_eq!([5, 10, 15, 20], *r);
 
-        let (q, r) = py.div(&px);
+        let (q, r) = py.div(&px, &Gf2_8);
         assert_eq!([3], *q);
         assert_eq!([6, 15, 9, 119], *r);
 
@@ -190,12 +342,12 @@ mod tests {
         let py = [3, 9, 17, 24, 75];
 
         let empty: [u8; 0] = [];
-        let (q, r) = px.div(&py);
+        let (q, r) = px.div(&py, &Gf2_8);
 
         assert_eq!(empty, *q);
         assert_eq!([0, 5, 10], *r);
 
-        let (q, r) = py.div(&px);
+        let (q, r) = py.div(&px, &Gf2_8);
         assert_eq!([3, 6, 17], *q);
         assert_eq!([113, 225], *r);
     }
@@ -207,7 +359,36 @@ mod tests {
         let answers = [213, 97, 132, 183, 244, 92];
 
         for i in 0..tests.len() {
-            assert_eq!(answers[i], p.eval(tests[i]));
+            assert_eq!(answers[i], p.eval(tests[i], &Gf2_8));
+        }
+    }
+
+    #[test]
+    fn derivative() {
+        // Even powers vanish in characteristic 2
+        let p = [7, 5, 10, 15, 20];
+        assert_eq!([0, 5, 0, 15], *p.derivative(&Gf2_8));
+
+        let constant = [42];
+        assert!(constant.derivative(&Gf2_8).is_empty());
+
+        // 3x^3 + 2x^2 + x + 5 over GF(7)
+        let field = ::gf::PrimeField::new(7, 3);
+        let p = [3u32, 2, 1, 5];
+        assert_eq!([2, 4, 1], *p.derivative(&field));
+    }
+
+    #[test]
+    fn compose() {
+        let p = [1, 0, 3];
+        let q = [2, 1];
+
+        // p(q(x)) == q(x)^2 + 3
+        let expected = q.mul(&q, &Gf2_8).add(&[3], &Gf2_8);
+        assert_eq!(*expected, *p.compose(&q, &Gf2_8));
+
+        for &x in &[0, 1, 7, 200] {
+            assert_eq!(p.eval(q.eval(x, &Gf2_8), &Gf2_8), p.compose(&q, &Gf2_8).eval(x, &Gf2_8));
         }
     }
 }
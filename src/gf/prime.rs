@@ -0,0 +1,268 @@
+use ::gf::{Field, FieldError};
+
+/// Maximum length of polynomials over prime fields
+const PRIME_POLYNOMIAL_MAX_LENGTH: usize = 1024;
+
+/// Prime field GF(p), `p < 2^32`, with caller-chosen primitive root as generator.
+///
+/// Elements are integers `0..p` with modular arithmetic; no tables are used.
+/// Codewords are at most `min(p - 1, 1024)` symbols long.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::{PrimeField, Encoder, Decoder};
+///
+/// // PDF417 field
+/// let field = PrimeField::new(929, 3);
+///
+/// let encoder = Encoder::with_field(field, 4);
+/// let decoder = Decoder::with_field(field, 4);
+///
+/// let mut encoded = encoder.encode(&[900, 1, 928, 4]);
+/// encoded[1] = 0;
+/// encoded[3] = 0;
+///
+/// let known_erasures = [3];
+/// assert_eq!(&[900, 1, 928, 4], decoder.correct(&encoded, Some(&known_erasures)).unwrap().data());
+/// ```
+///
+/// Constructors are `const fn`, so the field can also be a `static`:
+///
+/// ```rust
+/// use reed_solomon::PrimeField;
+///
+/// static GF929: PrimeField = PrimeField::new(929, 3);
+/// assert_eq!(929, GF929.characteristic());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PrimeField {
+    p: u32,
+    generator: u32,
+}
+
+impl PrimeField {
+    /// Constructs GF(`p`) with `generator` as primitive element.
+    ///
+    /// # Panics
+    /// Panics if `p` or `generator` is rejected by `try_new`.
+    pub const fn new(p: u32, generator: u32) -> Self {
+        match Self::try_new(p, generator) {
+            Ok(field) => field,
+            Err(FieldError::NotPrime) => panic!("field characteristic must be an odd prime"),
+            Err(FieldError::NotPrimitive) => panic!("generator is not a primitive root"),
+            Err(_) => panic!("unreachable"),
+        }
+    }
+
+    /// Constructs GF(`p`) with `generator` as primitive element.
+    ///
+    /// `p` must be an odd prime and `generator` a primitive root modulo `p`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use reed_solomon::{PrimeField, FieldError};
+    ///
+    /// assert!(PrimeField::try_new(929, 3).is_ok());
+    /// assert_eq!(PrimeField::try_new(929, 2).err(), Some(FieldError::NotPrimitive));
+    /// assert_eq!(PrimeField::try_new(931, 3).err(), Some(FieldError::NotPrime));
+    /// ```
+    pub const fn try_new(p: u32, generator: u32) -> Result<Self, FieldError> {
+        if p < 3 || !is_prime(p) {
+            return Err(FieldError::NotPrime);
+        }
+
+        if generator == 0 || generator >= p {
+            return Err(FieldError::NotPrimitive);
+        }
+
+        let field = PrimeField { p, generator };
+
+        // Generator order divides p - 1, it's p - 1 iff g^((p - 1) / q) != 1 for every prime q | p - 1
+        let mut rest = p - 1;
+        let mut q = 2;
+        while rest > 1 {
+            if q as u64 * q as u64 > rest as u64 {
+                q = rest;
+            }
+            if rest % q == 0 {
+                if field.pow_u32(generator, (p - 1) / q) == 1 {
+                    return Err(FieldError::NotPrimitive);
+                }
+                while rest % q == 0 {
+                    rest /= q;
+                }
+            }
+            q += 1;
+        }
+
+        Ok(field)
+    }
+
+    /// Field characteristic
+    pub const fn characteristic(&self) -> u32 {
+        self.p
+    }
+
+    #[inline]
+    const fn pow_u32(&self, x: u32, mut power: u32) -> u32 {
+        let p = self.p as u64;
+        let mut base = x as u64 % p;
+        let mut acc = 1u64;
+        while power != 0 {
+            if power & 1 != 0 {
+                acc = acc * base % p;
+            }
+            base = base * base % p;
+            power >>= 1;
+        }
+        acc as u32
+    }
+}
+
+const fn is_prime(n: u32) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let mut d = 2u32;
+    while d as u64 * d as u64 <= n as u64 {
+        if n % d == 0 {
+            return false;
+        }
+        d += 1;
+    }
+    true
+}
+
+impl Field for PrimeField {
+    type Elem = u32;
+    type Array = [u32; PRIME_POLYNOMIAL_MAX_LENGTH];
+
+    const ZEROS: Self::Array = [0; PRIME_POLYNOMIAL_MAX_LENGTH];
+
+    #[inline]
+    fn order(&self) -> usize {
+        self.p as usize
+    }
+
+    #[inline]
+    fn primitive(&self) -> u32 {
+        self.generator
+    }
+
+    #[inline]
+    fn add(&self, x: u32, y: u32) -> u32 {
+        ((x as u64 + y as u64) % self.p as u64) as u32
+    }
+
+    #[inline]
+    fn sub(&self, x: u32, y: u32) -> u32 {
+        ((x as u64 + self.p as u64 - y as u64) % self.p as u64) as u32
+    }
+
+    #[inline]
+    fn mul(&self, x: u32, y: u32) -> u32 {
+        (x as u64 * y as u64 % self.p as u64) as u32
+    }
+
+    #[inline]
+    fn div(&self, x: u32, y: u32) -> u32 {
+        debug_assert!(y != 0);
+        self.mul(x, self.inverse(y))
+    }
+
+    #[inline]
+    fn pow(&self, x: u32, power: i32) -> u32 {
+        if x == 0 {
+            return if power == 0 { 1 } else { 0 };
+        }
+
+        // Fermat's little theorem: x^(p - 1) == 1
+        let n = (self.p - 1) as i64;
+        let mut e = power as i64 % n;
+        if e < 0 {
+            e += n;
+        }
+        self.pow_u32(x, e as u32)
+    }
+
+    #[inline]
+    fn inverse(&self, x: u32) -> u32 {
+        debug_assert!(x != 0);
+        self.pow_u32(x, self.p - 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+    use ::{Encoder, Decoder};
+
+    #[test]
+    fn arithmetic() {
+        let field = PrimeField::new(929, 3);
+        for x in 1..929 {
+            assert_eq!(1, field.mul(x, field.inverse(x)));
+            assert_eq!(0, field.add(x, field.neg(x)));
+            assert_eq!(x, field.div(field.mul(x, 17), 17));
+            assert_eq!(field.inverse(x), field.pow(x, -1));
+        }
+        assert_eq!(928, field.sub(0, 1));
+        assert_eq!(1, field.pow(3, 928));
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert_eq!(Some(FieldError::NotPrime), PrimeField::try_new(2, 1).err());
+        assert_eq!(Some(FieldError::NotPrime), PrimeField::try_new(931, 3).err());
+        assert_eq!(Some(FieldError::NotPrime), PrimeField::try_new(65535, 3).err());
+        assert_eq!(Some(FieldError::NotPrimitive), PrimeField::try_new(929, 0).err());
+        assert_eq!(Some(FieldError::NotPrimitive), PrimeField::try_new(929, 929).err());
+        // 2 is a quadratic residue modulo 929
+        assert_eq!(Some(FieldError::NotPrimitive), PrimeField::try_new(929, 2).err());
+        assert!(PrimeField::try_new(65537, 3).is_ok());
+        assert!(PrimeField::try_new(2147483647, 7).is_ok());
+    }
+
+    #[test]
+    fn generator_poly_roots() {
+        let field = PrimeField::new(929, 3);
+        let encoder = Encoder::with_field(field, 6);
+        let encoded = encoder.encode(&[1, 2, 3, 4, 5, 6, 7, 8]);
+
+        // Codeword must vanish at every root of the generator polynomial
+        let decoder = Decoder::with_field(field, 6);
+        assert!(!decoder.is_corrupted(&encoded));
+    }
+
+    #[test]
+    fn errors_and_erasures() {
+        for &(p, g) in &[(929, 3), (65537, 3), (2147483647, 7)] {
+            let field = PrimeField::new(p, g);
+            let ecc_len = 10;
+            let encoder = Encoder::with_field(field, ecc_len);
+            let decoder = Decoder::with_field(field, ecc_len);
+
+            let data: Vec<u32> = (0..300u64).map(|x| (x * 7919 % p as u64) as u32).collect();
+            let encoded = encoder.encode(&data);
+
+            // 3 errors and 4 erasures: 2 * 3 + 4 <= 10
+            let mut corrupted = encoded;
+            corrupted[0] = field.add(corrupted[0], 1);
+            corrupted[150] = field.sub(corrupted[150], 5);
+            corrupted[305] = field.add(corrupted[305], p / 2);
+            let erasures = [7, 42, 299, 309];
+            for &pos in erasures.iter() {
+                corrupted[pos as usize] = field.add(corrupted[pos as usize], 1);
+            }
+
+            let (recovered, fixed) = decoder.correct_err_count(&corrupted, Some(&erasures)).unwrap();
+            assert_eq!(&data[..], recovered.data());
+            assert_eq!(&encoded[..], &recovered[..]);
+            assert_eq!(7, fixed);
+
+            corrupted[1] = field.add(corrupted[1], 1);
+            assert!(decoder.correct(&corrupted, Some(&erasures)).is_err());
+        }
+    }
+}
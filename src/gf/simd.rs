@@ -0,0 +1,200 @@
+use ::gf::gf2_8::{LOW, HIGH};
+
+/// Adds `c * src[i]` to `dst[i]` over the shorter of both slices in GF(2^8) with
+/// 0x11d primitive polynomial.
+///
+/// On x86_64 the longest prefix of 16-byte multiples goes through SSSE3 or AVX2 `pshufb`
+/// kernels with nibble tables of `c`, when the CPU supports them, which is detected at runtime.
+/// The rest is multiplied with `mul`.
+#[inline]
+pub fn mul_add_with<M>(c: u8, src: &[u8], dst: &mut [u8], mul: M)
+    where M: Fn(u8, u8) -> u8
+{
+    let len = if src.len() < dst.len() { src.len() } else { dst.len() };
+    let (src, dst) = (&src[..len], &mut dst[..len]);
+
+    let done = mul_add_vector(c, src, dst);
+    for (d, s) in dst[done..].iter_mut().zip(src[done..].iter()) {
+        *d ^= mul(c, *s);
+    }
+}
+
+/// Vector kernel for a prefix of equal length `src` and `dst`, returns the prefix length
+#[inline]
+fn mul_add_vector(c: u8, src: &[u8], dst: &mut [u8]) -> usize {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if src.len() >= 16 && c != 0 {
+            match detect() {
+                Kernel::Avx2 => return unsafe { mul_add_avx2(c, src, dst) },
+                Kernel::Ssse3 => return unsafe { mul_add_ssse3(c, src, dst) },
+                Kernel::Scalar => {}
+            }
+        }
+    }
+
+    let _ = (c, src, dst);
+    0
+}
+
+#[cfg(target_arch = "x86_64")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Kernel {
+    Scalar = 1,
+    Ssse3 = 2,
+    Avx2 = 3,
+}
+
+/// Runtime SSSE3 and AVX2 detection, cached after the first call
+#[cfg(target_arch = "x86_64")]
+#[inline]
+fn detect() -> Kernel {
+    use core::sync::atomic::{AtomicU8, Ordering};
+
+    const UNKNOWN: u8 = 0;
+    static DETECTED: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+    if cfg!(target_feature = "avx2") {
+        return Kernel::Avx2;
+    }
+
+    match DETECTED.load(Ordering::Relaxed) {
+        1 => Kernel::Scalar,
+        2 => Kernel::Ssse3,
+        3 => Kernel::Avx2,
+        _ => {
+            let kernel = cpuid_kernel();
+            DETECTED.store(kernel as u8, Ordering::Relaxed);
+            kernel
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[allow(unused_unsafe)]
+fn cpuid_kernel() -> Kernel {
+    use core::arch::x86_64::{__cpuid, __cpuid_count};
+
+    let leaf1 = unsafe { __cpuid(1) };
+    let ssse3 = leaf1.ecx & (1 << 9) != 0;
+    let osxsave = leaf1.ecx & (1 << 27) != 0;
+    let avx = leaf1.ecx & (1 << 28) != 0;
+
+    // AVX2 also needs the OS to save YMM registers
+    let avx2 = osxsave && avx
+        && unsafe { __cpuid(0) }.eax >= 7
+        && unsafe { __cpuid_count(7, 0) }.ebx & (1 << 5) != 0
+        && unsafe { xcr0() } & 0b110 == 0b110;
+
+    if avx2 {
+        Kernel::Avx2
+    } else if ssse3 {
+        Kernel::Ssse3
+    } else {
+        Kernel::Scalar
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "xsave")]
+unsafe fn xcr0() -> u64 {
+    core::arch::x86_64::_xgetbv(0)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "ssse3")]
+unsafe fn mul_add_ssse3(c: u8, src: &[u8], dst: &mut [u8]) -> usize {
+    use core::arch::x86_64::*;
+
+    let low = _mm_loadu_si128(LOW[c as usize].as_ptr() as *const __m128i);
+    let high = _mm_loadu_si128(HIGH[c as usize].as_ptr() as *const __m128i);
+    let mask = _mm_set1_epi8(0x0f);
+
+    let len = src.len() & !15;
+    let mut i = 0;
+    while i < len {
+        let x = _mm_loadu_si128(src.as_ptr().add(i) as *const __m128i);
+        let lo = _mm_shuffle_epi8(low, _mm_and_si128(x, mask));
+        let hi = _mm_shuffle_epi8(high, _mm_and_si128(_mm_srli_epi64(x, 4), mask));
+
+        let d = dst.as_mut_ptr().add(i) as *mut __m128i;
+        _mm_storeu_si128(d, _mm_xor_si128(_mm_loadu_si128(d), _mm_xor_si128(lo, hi)));
+        i += 16;
+    }
+    len
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn mul_add_avx2(c: u8, src: &[u8], dst: &mut [u8]) -> usize {
+    use core::arch::x86_64::*;
+
+    let low = _mm256_broadcastsi128_si256(_mm_loadu_si128(LOW[c as usize].as_ptr() as *const __m128i));
+    let high = _mm256_broadcastsi128_si256(_mm_loadu_si128(HIGH[c as usize].as_ptr() as *const __m128i));
+    let mask = _mm256_set1_epi8(0x0f);
+
+    let len = src.len() & !31;
+    let mut i = 0;
+    while i < len {
+        let x = _mm256_loadu_si256(src.as_ptr().add(i) as *const __m256i);
+        let lo = _mm256_shuffle_epi8(low, _mm256_and_si256(x, mask));
+        let hi = _mm256_shuffle_epi8(high, _mm256_and_si256(_mm256_srli_epi64(x, 4), mask));
+
+        let d = dst.as_mut_ptr().add(i) as *mut __m256i;
+        _mm256_storeu_si256(d, _mm256_xor_si256(_mm256_loadu_si256(d), _mm256_xor_si256(lo, hi)));
+        i += 32;
+    }
+
+    // A single 16-byte block may be left
+    len + mul_add_ssse3(c, &src[len..], &mut dst[len..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::gf;
+
+    fn check<K>(kernel: K)
+        where K: Fn(u8, &[u8], &mut [u8]) -> usize
+    {
+        let src: [u8; 100] = core::array::from_fn(|i| (i * 37 + 11) as u8);
+        for c in 0..256usize {
+            let c = c as u8;
+            for &len in &[0usize, 15, 16, 17, 31, 32, 33, 48, 64, 100] {
+                let mut dst: [u8; 100] = core::array::from_fn(|i| (i * 5) as u8);
+                let done = kernel(c, &src[..len], &mut dst[..len]);
+                assert!(done <= len && done % 16 == 0);
+                for i in 0..100 {
+                    let expected = if i < done { (i * 5) as u8 ^ gf::mul(c, src[i]) } else { (i * 5) as u8 };
+                    assert_eq!(expected, dst[i]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn kernels() {
+        check(mul_add_vector);
+
+        #[cfg(target_arch = "x86_64")]
+        {
+            let kernel = detect();
+            if kernel != Kernel::Scalar {
+                check(|c, src, dst| unsafe { mul_add_ssse3(c, src, dst) });
+            }
+            if kernel == Kernel::Avx2 {
+                check(|c, src, dst| unsafe { mul_add_avx2(c, src, dst) });
+            }
+        }
+    }
+
+    #[test]
+    fn mul_add() {
+        let src: [u8; 77] = core::array::from_fn(|i| (i * 13) as u8);
+        let mut dst = [0x42; 70];
+        mul_add_with(0x8e, &src, &mut dst, gf::mul);
+        for i in 0..70 {
+            assert_eq!(0x42 ^ gf::mul(0x8e, src[i]), dst[i]);
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use ::gf::poly::Polynom;
+use ::gf::{Field, Symbol, Gf2_8};
+
+/// Linear feedback shift register over a field
+///
+/// Generates `s[n] = -(c[1] * s[n - 1] + ... + c[L] * s[n - L])`, where
+/// `C(x) = 1 + c[1] x + ... + c[L] x^L` is the connection polynomial and `L` is the length
+/// of the register. Returned by `berlekamp_massey`.
+#[derive(Debug)]
+pub struct Lfsr<F: Field = Gf2_8> {
+    connection: Polynom<F>,
+    complexity: usize,
+}
+
+impl<F: Field> Lfsr<F> {
+    /// Connection polynomial `C(x)`, highest degree first, `L + 1` coefficients long.
+    /// Its constant term is one, higher coefficients may be zero.
+    pub fn connection(&self) -> &Polynom<F> {
+        &self.connection
+    }
+
+    /// Length `L` of the register: the linear complexity of the synthesized sequence
+    pub fn linear_complexity(&self) -> usize {
+        self.complexity
+    }
+
+    /// Next symbol after `state`, the last `L` symbols of the sequence, oldest first
+    ///
+    /// # Panics
+    /// Panics if `state` is shorter than `L`.
+    pub fn next(&self, state: &[F::Elem], field: &F) -> F::Elem {
+        let state = &state[state.len() - self.complexity..];
+
+        // c[i] is connection[L - i], and multiplies s[n - i], which is state[L - i]
+        let mut acc = F::Elem::ZERO;
+        for (c, s) in self.connection[..self.complexity].iter().zip(state.iter()) {
+            acc = field.add(acc, field.mul(*c, *s));
+        }
+        field.neg(acc)
+    }
+
+    /// Runs the register: the first `L` symbols of `sequence` are the initial state,
+    /// the rest are overwritten with generated symbols.
+    ///
+    /// # Panics
+    /// Panics if `sequence` is shorter than `L`.
+    pub fn generate(&self, sequence: &mut [F::Elem], field: &F) {
+        for n in self.complexity..sequence.len() {
+            sequence[n] = self.next(&sequence[..n], field);
+        }
+    }
+}
+
+impl<F: Field> Copy for Lfsr<F> where F::Array: Copy {}
+
+impl<F: Field> Clone for Lfsr<F> {
+    fn clone(&self) -> Self {
+        Lfsr {
+            connection: self.connection.clone(),
+            complexity: self.complexity,
+        }
+    }
+}
+
+/// Berlekamp-Massey algorithm: the shortest LFSR generating `sequence`.
+///
+/// `sequence` must be shorter than polynomial capacity of the field.
+///
+/// # Example
+/// ```rust
+/// use reed_solomon::{berlekamp_massey, Gf2_8};
+///
+/// // Fibonacci sequence over GF(2^8): s[n] = s[n - 1] + s[n - 2]
+/// let mut sequence = [1u8, 2, 0, 0, 0, 0, 0, 0];
+/// for n in 2..sequence.len() {
+///     sequence[n] = sequence[n - 1] ^ sequence[n - 2];
+/// }
+///
+/// let lfsr = berlekamp_massey(&sequence, &Gf2_8);
+/// assert_eq!(2, lfsr.linear_complexity());
+/// assert_eq!([1, 1, 1], **lfsr.connection());
+///
+/// let mut generated = [1, 2, 0, 0, 0, 0, 0, 0];
+/// lfsr.generate(&mut generated, &Gf2_8);
+/// assert_eq!(sequence, generated);
+/// ```
+pub fn berlekamp_massey<F: Field>(sequence: &[F::Elem], field: &F) -> Lfsr<F> {
+    // Connection polynomials are kept lowest degree first, and reversed at the end
+    let mut current = Polynom::<F>::from(&[F::Elem::ONE][..]);
+    let mut previous = current.clone();
+    let mut complexity = 0;
+    // Shift of `previous` and its discrepancy
+    let mut shift = 1;
+    let mut previous_delta = F::Elem::ONE;
+
+    for n in 0..sequence.len() {
+        let mut delta = sequence[n];
+        for i in 1..(complexity + 1).min(current.len()) {
+            delta = field.add(delta, field.mul(current[i], sequence[n - i]));
+        }
+
+        if delta == F::Elem::ZERO {
+            shift += 1;
+            continue;
+        }
+
+        // current - delta / previous_delta * x^shift * previous
+        let coef = field.div(delta, previous_delta);
+        let mut next = current.clone();
+        if next.len() < previous.len() + shift {
+            next.set_length(previous.len() + shift);
+        }
+        for (i, x) in previous.iter().enumerate() {
+            next[i + shift] = field.sub(next[i + shift], field.mul(coef, *x));
+        }
+
+        if 2 * complexity <= n {
+            complexity = n + 1 - complexity;
+            previous = current;
+            previous_delta = delta;
+            shift = 1;
+        } else {
+            shift += 1;
+        }
+        current = next;
+    }
+
+    current.set_length(complexity + 1);
+    Lfsr {
+        connection: current.reverse(),
+        complexity,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::gf::PrimeField;
+
+    #[test]
+    fn binary_sequence() {
+        // s[n] = s[n - 3] + s[n - 4] over GF(2^8)
+        let mut sequence = [0u8; 30];
+        sequence[..4].copy_from_slice(&[3, 0, 7, 200]);
+        for n in 4..sequence.len() {
+            sequence[n] = sequence[n - 3] ^ sequence[n - 4];
+        }
+
+        let lfsr = berlekamp_massey(&sequence, &Gf2_8);
+        assert_eq!(4, lfsr.linear_complexity());
+        assert_eq!([1, 1, 0, 0, 1], **lfsr.connection());
+
+        let mut generated = [0; 30];
+        generated[..4].copy_from_slice(&sequence[..4]);
+        lfsr.generate(&mut generated, &Gf2_8);
+        assert_eq!(sequence, generated);
+    }
+
+    #[test]
+    fn complexity() {
+        let lfsr = berlekamp_massey(&[0u8; 10], &Gf2_8);
+        assert_eq!(0, lfsr.linear_complexity());
+        assert_eq!([1], **lfsr.connection());
+
+        let lfsr = berlekamp_massey(&[], &Gf2_8);
+        assert_eq!(0, lfsr.linear_complexity());
+
+        // A single non-zero symbol after n zeros needs a register of length n + 1
+        let lfsr = berlekamp_massey(&[0, 0, 0, 5], &Gf2_8);
+        assert_eq!(4, lfsr.linear_complexity());
+        assert_eq!(5, lfsr.connection().len());
+    }
+
+    #[test]
+    fn prime_field() {
+        // s[n] = 2 s[n - 1] + 5 s[n - 2] + 1 s[n - 3] over GF(7)
+        let field = PrimeField::new(7, 3);
+        let mut sequence = [0u32; 20];
+        sequence[..3].copy_from_slice(&[0, 0, 1]);
+        for n in 3..sequence.len() {
+            sequence[n] = (2 * sequence[n - 1] + 5 * sequence[n - 2] + sequence[n - 3]) % 7;
+        }
+
+        let lfsr = berlekamp_massey(&sequence, &field);
+        assert_eq!(3, lfsr.linear_complexity());
+        // 1 - 2x - 5x^2 - x^3
+        assert_eq!([6, 2, 5, 1], **lfsr.connection());
+
+        let mut generated = [0; 20];
+        generated[..3].copy_from_slice(&sequence[..3]);
+        lfsr.generate(&mut generated, &field);
+        assert_eq!(sequence, generated);
+    }
+}
@@ -43,6 +43,60 @@
 //! }
 //! ```
 //!
+//! # Fields
+//! `Encoder`, `Decoder` and `Buffer` are generic over the `Field` trait, which describes
+//! the Galois Field the code is built over. `Encoder::new` and `Decoder::new` use the default
+//! `Gf2_8` field (0x11d primitive polynomial, generator 2); `Encoder::with_field` and
+//! `Decoder::with_field` accept any other `Field` implementation, e.g. `BinaryField`
+//! GF(2^3) to GF(2^8) built at runtime from a caller-chosen primitive polynomial,
+//! `Gf2_16` with 16-bit symbols for codewords up to 4096 symbols long, or 65535 with `alloc`,
+//! `Gf2_32` and `Gf2_64` with wide symbols and carry-less multiplication instead of tables,
+//! or `PrimeField` for codes over GF(p).
+//!
+//! Roots of the generator polynomial are `a^0 ... a^(ecc_len - 1)` of the primitive element `a`
+//! by default. `Encoder::with_roots` and `Decoder::with_roots` take the first consecutive root
+//! exponent (fcr) and the root spacing (prim) of other standards, e.g. fcr 112 and prim 11
+//! of CCSDS, or fcr 1 of Data Matrix.
+//!
+//! `Gf2_8` multiplies with log/antilog tables (768 bytes). The same field is also available
+//! with other multiplication backends, which produce identical codewords:
+//! `Gf2_8Product` with a 64 KiB table of all products, `Gf2_8Nibble` with 8 KiB of 4-bit
+//! split tables, and table-free `Gf2_8Bitwise` for targets that cannot spare memory for tables.
+//!
+//! `ReedSolomon<N, K>` fixes a GF(2^8) code with `N` symbols long codewords of `K` data symbols
+//! at compile time, e.g. `ReedSolomon<255, 223>`: its generator polynomial and roots are
+//! constants, and parameters that don't make a code fail to build.
+//!
+//! `Gf256` wraps a single `Gf2_8` element and implements arithmetic operators on it,
+//! for code that needs field arithmetic outside of encoding and decoding.
+//!
+//! # Polynomials
+//! `Polynom` is the fixed-capacity polynomial type used by the encoder and decoder.
+//! It supports addition, multiplication, division with remainder, evaluation, scaling,
+//! formal derivative, composition, GCD and the extended Euclidean algorithm, multipoint
+//! evaluation and Lagrange or Newton interpolation
+//! over any `Field`, for custom checks or algebra on top of the codes.
+//! `berlekamp_massey` synthesizes the shortest `Lfsr` generating any sequence over a field.
+//!
+//! # Allocation
+//! The crate is `no_std` and without the `alloc` feature doesn't allocate: polynomials
+//! and buffers are fixed-size arrays on the stack, sized by the field. Wrapping a field into
+//! `Capacity<F, N>` limits them to `N` symbols, e.g. `Encoder<Capacity<Gf2_8, 32>>` for small
+//! stacks of embedded targets. With the `alloc` feature, `Gf2_16` polynomials are `Vec`-backed,
+//! and wrapping any other field into `Heap` makes them `Vec`-backed too, e.g.
+//! `Decoder<Heap<PrimeField>>` works on codewords longer than 1024 symbols, and buffers
+//! are cheap to move.
+//!
+//! The `alloc` feature also enables `FftEncoder` and `FftDecoder`: a GF(2^16) code
+//! with O(n log n) encoding and erasure-only decoding using the Lin-Chung-Han additive FFT,
+//! for codes with thousands of parity symbols.
+//!
+//! # Constant time
+//! Table lookups and early exits make the default arithmetic and decoder leak timing
+//! information about the data. To decode secrets, e.g. in secret sharing, use the table-free
+//! and branch-free `Gf2_8ConstantTime` field (or any other `ConstantTime` field) with
+//! `Decoder::correct_constant_time`.
+//!
 //! # Unsafe
 //! This library uses some slices indexind that is boundary checked.
 //!
@@ -55,8 +109,24 @@
 //! Galois field arithmetic operations. For example, Galois field multiply requires test for 0,
 //! two table look-ups, modulo add, and anti-log table look-up.
 //!
-//! Besides this performance bound, current implementation is not very optimal
-//! and performs some unnecessary memcpys.
+//! `encode` and `correct` copy the message into the returned `Buffer`;
+//! `encode_in_place` and `compute_parity` write parity into caller's slices without copying.
+//!
+//! `TableEncoder` trades a table of 256 rows of `ecc_len` precomputed products for a faster
+//! encoding loop. `cargo run --release --example bandwidth` prints `bandwidth` of `Encoder`
+//! and `table_bandwidth` of `TableEncoder` side by side for RS(255, 251), RS(255, 239)
+//! and RS(255, 223) to compare them on your machine.
+//!
+//! On x86_64 GF(2^8) multiply-accumulate over slices, the inner loop of `Encoder` and of
+//! syndrome computation, uses SSSE3 or AVX2 `pshufb` kernels when the CPU supports them.
+//! Support is detected at runtime, other targets use the scalar `no_std` path.
+//!
+//! `Encoder::encode_batch` and `Decoder::is_corrupted_batch` process 16, 32 or 64 codewords
+//! in lockstep, one vector lane per codeword, so that each multiplication by a generator
+//! coefficient or a root runs once over all of them in the `pshufb` kernels above.
+//! The bandwidth example measures them with 32 lanes as `batch_bandwidth` and `batch_check`,
+//! next to `Encoder` and to `Decoder::is_corrupted` (the decoder with 0 errors) one codeword
+//! at a time.
 //!
 //! Encoder bandwidth using one Sandy Bridge core operating on 2.8 `GHz`:
 //! <style type="text/css">
@@ -167,6 +237,12 @@
 
 #![no_std]
 
+#[cfg(test)]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 const POLYNOMIAL_MAX_LENGTH: usize = 256;
 
 #[macro_use]
@@ -175,8 +251,28 @@ mod gf;
 mod encoder;
 mod decoder;
 mod buffer;
+mod lfsr;
+mod batch;
+mod fixed;
+#[cfg(feature = "alloc")]
+mod fft;
 
 pub use encoder::Encoder;
+pub use encoder::EncoderError;
+pub use encoder::EncoderState;
+pub use encoder::TableEncoder;
+pub use fixed::ReedSolomon;
 pub use decoder::Decoder;
 pub use decoder::DecoderError;
 pub use buffer::Buffer;
+pub use lfsr::{Lfsr, berlekamp_massey};
+pub use gf::poly::{Polynom, Storage};
+pub use gf::{Field, FieldError, Symbol, Gf2_8, Gf2_16, BinaryField, PrimeField};
+pub use gf::{Gf2_8Product, Gf2_8Nibble, Gf2_8Bitwise, Gf2_8ConstantTime, ConstantTime};
+pub use gf::{Gf256, ArithmeticError};
+pub use gf::{Gf2_32, Gf2_64};
+pub use gf::Capacity;
+#[cfg(feature = "alloc")]
+pub use gf::Heap;
+#[cfg(feature = "alloc")]
+pub use fft::{FftEncoder, FftDecoder};
@@ -33,3 +33,63 @@ macro_rules! uncheck_mut {
         }
     }
 }
+
+/// Implements `Field` methods of a wrapper by delegating them to the wrapped field `self.0`
+macro_rules! delegate_field_ops {
+    () => {
+        #[inline]
+        fn order(&self) -> usize {
+            self.0.order()
+        }
+
+        #[inline]
+        fn contains(&self, x: Self::Elem) -> bool {
+            self.0.contains(x)
+        }
+
+        #[inline]
+        fn primitive(&self) -> Self::Elem {
+            self.0.primitive()
+        }
+
+        #[inline]
+        fn add(&self, x: Self::Elem, y: Self::Elem) -> Self::Elem {
+            self.0.add(x, y)
+        }
+
+        #[inline]
+        fn sub(&self, x: Self::Elem, y: Self::Elem) -> Self::Elem {
+            self.0.sub(x, y)
+        }
+
+        #[inline]
+        fn neg(&self, x: Self::Elem) -> Self::Elem {
+            self.0.neg(x)
+        }
+
+        #[inline]
+        fn mul(&self, x: Self::Elem, y: Self::Elem) -> Self::Elem {
+            self.0.mul(x, y)
+        }
+
+        #[inline]
+        fn div(&self, x: Self::Elem, y: Self::Elem) -> Self::Elem {
+            self.0.div(x, y)
+        }
+
+        #[inline]
+        fn pow(&self, x: Self::Elem, power: i32) -> Self::Elem {
+            self.0.pow(x, power)
+        }
+
+        #[inline]
+        fn inverse(&self, x: Self::Elem) -> Self::Elem {
+            self.0.inverse(x)
+        }
+
+        #[inline]
+        fn mul_add_slice(&self, c: Self::Elem, src: &[Self::Elem], dst: &mut [Self::Elem]) {
+            self.0.mul_add_slice(c, src, dst)
+        }
+    }
+}
@@ -2,6 +2,7 @@ extern crate reed_solomon;
 
 use reed_solomon::Encoder;
 use reed_solomon::Decoder;
+use reed_solomon::Field;
 
 const ECC_LEN: usize = 8;
 
@@ -23,7 +24,7 @@ fn helloworld() {
     }
 
     // Try to recover data
-    let recovered = dec.correct(&mut corrupted, None).unwrap();
+    let recovered = dec.correct(&corrupted, None).unwrap();
 
     assert_eq!(data, recovered.data());
 }
@@ -47,7 +48,36 @@ fn with_erasures() {
 
     // Try to recover data
     let known_erasures = [0, 1, 2];
-    let recovered = dec.correct(&mut corrupted, Some(&known_erasures)).unwrap();
+    let recovered = dec.correct(&corrupted, Some(&known_erasures)).unwrap();
 
     assert_eq!(data, recovered.data());
 }
+
+#[test]
+fn small_fields() {
+    use reed_solomon::{BinaryField, DecoderError};
+
+    for &poly in &[0xb, 0x13, 0x25, 0x43, 0x89] {
+        let field = BinaryField::new(poly, 2);
+        let max_symbol = (field.order() - 1) as u8;
+
+        let enc = Encoder::with_field(&field, 2);
+        let dec = Decoder::with_field(&field, 2);
+
+        // Longest possible codeword: 2^m - 1 symbols
+        let data: Vec<u8> = (0..field.order() - 3).map(|x| x as u8 & max_symbol).collect();
+        let encoded = enc.encode(&data);
+        assert_eq!(field.order() - 1, encoded.len());
+
+        let mut corrupted = encoded;
+        corrupted[1] ^= max_symbol;
+        assert_eq!(&data[..], dec.correct(&corrupted, None).unwrap().data());
+
+        corrupted[1] = max_symbol + 1;
+        assert!(dec.is_corrupted(&corrupted));
+        match dec.correct(&corrupted, None) {
+            Err(DecoderError::InvalidSymbol) => (),
+            _ => panic!("out of field symbol accepted"),
+        }
+    }
+}